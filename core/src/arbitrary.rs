@@ -0,0 +1,522 @@
+//! [proptest] strategies which generate random JSON values matching the schema of a [ZodType].
+//!
+//! ```
+//! # use zod_core::arbitrary::ArbitraryJson;
+//! # use proptest::strategy::{Strategy, ValueTree};
+//! # use proptest::test_runner::TestRunner;
+//! let mut runner = TestRunner::default();
+//! let value = <Vec<u8>>::arbitrary_json().new_tree(&mut runner).unwrap().current();
+//! assert!(value.is_array());
+//! ```
+//!
+//! Recursive types are cut off after a couple of references by generating empty arrays and
+//! records and `null` for nullable values.
+
+use std::fmt::Debug;
+
+use proptest::prelude::*;
+use proptest::strategy::{Just, Union};
+use proptest::test_runner::{Config, TestCaseError, TestRunner};
+use serde_json::{Map, Value};
+
+use crate::ast::{
+    integer_checks, IntegerMode, IpVersion, NumberCheck, Numeric, Refinement, StringCheck,
+    ZodObject, ZodSchema,
+};
+use crate::validate::{check_refinement, known_keys, literal_value, resolve, validate};
+use crate::validation::js_regex;
+use crate::ZodType;
+
+/// references are only followed until this depth to generate finite values for recursive types
+const MAX_DEPTH: usize = 4;
+
+/// Every [ZodType] gets strategies for JSON values matching its schemas.
+pub trait ArbitraryJson: ZodType {
+    /// values matching [ZodType::schema]
+    fn arbitrary_json() -> BoxedStrategy<Value> {
+        schema_strategy(&Self::schema())
+    }
+
+    /// values matching [ZodType::input_schema]
+    fn arbitrary_input_json() -> BoxedStrategy<Value> {
+        schema_strategy(&Self::input_schema())
+    }
+}
+
+impl<T: ZodType + ?Sized> ArbitraryJson for T {}
+
+pub fn schema_strategy(schema: &ZodSchema) -> BoxedStrategy<Value> {
+    strategy(schema, &[], 0)
+}
+
+/// Generate arbitrary values of `T`, serialize them with serde and check that they conform to the
+/// schema of `T`.
+///
+/// # Panics
+/// If a value does not conform to the schema.
+#[track_caller]
+pub fn assert_arbitrary_conforms<T>()
+where
+    T: ZodType + Arbitrary + serde::Serialize + Debug,
+{
+    let result = runner().run(&any::<T>(), |value| {
+        let json =
+            serde_json::to_value(&value).map_err(|err| TestCaseError::fail(err.to_string()))?;
+
+        validate::<T>(&json).map_err(|errors| {
+            TestCaseError::fail(format!(
+                "{json} does not conform to {}: {errors}",
+                T::schema()
+            ))
+        })
+    });
+
+    if let Err(err) = result {
+        panic!("{err}")
+    }
+}
+
+/// Generate arbitrary JSON values matching the input schema of `T` and check that serde
+/// deserializes them.
+///
+/// # Panics
+/// If a value cannot be deserialized.
+#[track_caller]
+pub fn assert_arbitrary_input_deserializes<T>()
+where
+    T: ZodType + serde::de::DeserializeOwned,
+{
+    let result = runner().run(&T::arbitrary_input_json(), |json| {
+        serde_json::from_value::<T>(json.clone())
+            .map(drop)
+            .map_err(|err| {
+                TestCaseError::fail(format!(
+                    "{json} matches {} but does not deserialize: {err}",
+                    T::input_schema()
+                ))
+            })
+    });
+
+    if let Err(err) = result {
+        panic!("{err}")
+    }
+}
+
+/// a runner which doesn't try to persist failures next to the source of the caller
+fn runner() -> TestRunner {
+    TestRunner::new(Config {
+        failure_persistence: None,
+        ..Config::default()
+    })
+}
+
+fn strategy(schema: &ZodSchema, refinements: &[Refinement], depth: usize) -> BoxedStrategy<Value> {
+    match schema {
+        ZodSchema::String(checks) => string(checks, refinements),
+        ZodSchema::Number(checks) => number(checks, refinements),
+        ZodSchema::Integer { min, max, mode } => match mode {
+            IntegerMode::Number => number(&integer_checks(*min, *max), refinements),
+            IntegerMode::BigInt | IntegerMode::String => (as_i128(*min)..=as_i128(*max))
+                .prop_map(|n| Value::String(n.to_string()))
+                .boxed(),
+        },
+        ZodSchema::Array(inner) => {
+            let (min, max) = length(refinements);
+
+            if depth >= MAX_DEPTH && min == 0 {
+                return Just(Value::Array(Vec::new())).boxed();
+            }
+
+            proptest::collection::vec(strategy(inner, &[], depth), min..=max)
+                .prop_map(Value::Array)
+                .boxed()
+        }
+        ZodSchema::Refined(inner, more) => {
+            let all = refinements.iter().chain(more).cloned().collect::<Vec<_>>();
+            strategy(inner, &all, depth)
+        }
+        ZodSchema::Described(inner, _) | ZodSchema::Branded(inner, _) => {
+            strategy(inner, refinements, depth)
+        }
+        ZodSchema::Lazy {
+            ns_name,
+            name,
+            args,
+        } => match resolve(ns_name, name, args) {
+            Some(schema) if depth <= MAX_DEPTH * 8 => strategy(&schema, refinements, depth + 1),
+            _ => Just(Value::Null).boxed(),
+        },
+        other => filtered(unrefined(other, depth), refinements),
+    }
+}
+
+/// values of schemas which don't interpret refinements themselves
+fn unrefined(schema: &ZodSchema, depth: usize) -> BoxedStrategy<Value> {
+    match schema {
+        ZodSchema::Boolean => any::<bool>().prop_map(Value::Bool).boxed(),
+        ZodSchema::Null | ZodSchema::Unknown | ZodSchema::Raw(_) => Just(Value::Null).boxed(),
+        // no value conforms, but uninhabited types can't be instantiated in rust either
+        ZodSchema::Never => Just(Value::Null).boxed(),
+        ZodSchema::Literal(literal) => Just(literal_value(literal)).boxed(),
+        ZodSchema::Enum(members) | ZodSchema::NativeEnum { members, .. } => {
+            let values = members
+                .iter()
+                .map(|member| literal_value(&member.value))
+                .collect::<Vec<_>>();
+
+            proptest::sample::select(values).boxed()
+        }
+
+        ZodSchema::Record(_, _) if depth >= MAX_DEPTH => Just(Value::Object(Map::new())).boxed(),
+
+        ZodSchema::Record(key, value) => {
+            let entry = (strategy(key, &[], depth), strategy(value, &[], depth));
+
+            proptest::collection::vec(entry, 0..=3)
+                .prop_map(|entries| {
+                    let map = entries
+                        .into_iter()
+                        .filter_map(|(k, v)| match k {
+                            Value::String(k) => Some((k, v)),
+                            _ => None,
+                        })
+                        .collect::<Map<_, _>>();
+
+                    Value::Object(map)
+                })
+                .boxed()
+        }
+
+        ZodSchema::Tuple(items) => items
+            .iter()
+            .map(|item| strategy(item, &[], depth))
+            .collect::<Vec<_>>()
+            .prop_map(Value::Array)
+            .boxed(),
+
+        ZodSchema::Object(obj) => object(obj, depth),
+
+        ZodSchema::Union(variants) | ZodSchema::DiscriminatedUnion { variants, .. } => {
+            let variants = if depth >= MAX_DEPTH {
+                &variants[..variants.len().min(1)]
+            } else {
+                &variants[..]
+            };
+
+            if variants.is_empty() {
+                Just(Value::Null).boxed()
+            } else {
+                Union::new(variants.iter().map(|variant| strategy(variant, &[], depth))).boxed()
+            }
+        }
+
+        // undefined can't be represented outside of objects
+        ZodSchema::Optional(inner) | ZodSchema::Defaulted(inner, _) => strategy(inner, &[], depth),
+
+        ZodSchema::Nullable(inner) | ZodSchema::Nullish(inner) => {
+            if depth >= MAX_DEPTH {
+                Just(Value::Null).boxed()
+            } else {
+                prop_oneof![Just(Value::Null), strategy(inner, &[], depth)].boxed()
+            }
+        }
+
+        ZodSchema::String(_)
+        | ZodSchema::Number(_)
+        | ZodSchema::Integer { .. }
+        | ZodSchema::Array(_)
+        | ZodSchema::Lazy { .. }
+        | ZodSchema::Refined(_, _)
+        | ZodSchema::Described(_, _)
+        | ZodSchema::Branded(_, _) => strategy(schema, &[], depth),
+    }
+}
+
+/// discard values which don't satisfy the refinements
+fn filtered(strategy: BoxedStrategy<Value>, refinements: &[Refinement]) -> BoxedStrategy<Value> {
+    if refinements.is_empty() {
+        return strategy;
+    }
+
+    let refinements = refinements.to_vec();
+    strategy
+        .prop_filter("refinements", move |value| {
+            refinements
+                .iter()
+                .all(|refinement| check_refinement(value, refinement).is_none())
+        })
+        .boxed()
+}
+
+/// Missing optional fields are omitted, flattened members are merged into the object and a
+/// catchall adds a couple of unknown keys.
+fn object(obj: &ZodObject, depth: usize) -> BoxedStrategy<Value> {
+    let fields = obj
+        .fields
+        .iter()
+        .map(|field| {
+            let name = field.name.clone();
+
+            let value = match &field.schema {
+                ZodSchema::Optional(_) | ZodSchema::Nullish(_) | ZodSchema::Defaulted(_, _)
+                    if depth >= MAX_DEPTH =>
+                {
+                    Just(None).boxed()
+                }
+                ZodSchema::Optional(inner) | ZodSchema::Defaulted(inner, _) => {
+                    proptest::option::of(strategy(inner, &[], depth)).boxed()
+                }
+                ZodSchema::Nullish(inner) => prop_oneof![
+                    Just(None),
+                    Just(Some(Value::Null)),
+                    strategy(inner, &[], depth).prop_map(Some)
+                ]
+                .boxed(),
+                other => strategy(other, &[], depth).prop_map(Some).boxed(),
+            };
+
+            value.prop_map(move |value| value.map(|value| (name.clone(), value)))
+        })
+        .collect::<Vec<_>>();
+
+    let extends = obj
+        .extends
+        .iter()
+        .map(|ext| strategy(ext, &[], depth))
+        .collect::<Vec<_>>();
+
+    let catchall = match &obj.catchall {
+        Some(catchall) if depth < MAX_DEPTH => {
+            let entry = ("[a-z]{1,8}", strategy(catchall, &[], depth));
+            proptest::collection::vec(entry, 0..=2).boxed()
+        }
+        _ => Just(Vec::new()).boxed(),
+    };
+
+    // omitted optional fields must not be generated as unknown keys
+    let known = known_keys(obj);
+
+    (fields, extends, catchall)
+        .prop_map(move |(fields, extends, catchall)| {
+            let mut map = fields.into_iter().flatten().collect::<Map<_, _>>();
+
+            for ext in extends {
+                if let Value::Object(ext) = ext {
+                    map.extend(ext)
+                }
+            }
+
+            for (key, value) in catchall {
+                if !known.contains(&key) {
+                    map.entry(key).or_insert(value);
+                }
+            }
+
+            Value::Object(map)
+        })
+        .boxed()
+}
+
+fn string(checks: &[StringCheck], refinements: &[Refinement]) -> BoxedStrategy<Value> {
+    let (min, max) = length(refinements);
+
+    let pattern = checks
+        .iter()
+        .find_map(|check| match check {
+            StringCheck::Regex(pattern) => Some(pattern.clone()),
+            _ => None,
+        })
+        .or_else(|| {
+            refinements.iter().find_map(|refinement| match refinement {
+                Refinement::Regex(pattern) => Some(pattern.clone()),
+                Refinement::Email => Some(String::from(r"[a-z]{1,8}@[a-z]{1,8}\.[a-z]{2,4}")),
+                Refinement::Url => Some(String::from(r"https://[a-z]{1,8}\.[a-z]{2,4}")),
+                _ => None,
+            })
+        });
+
+    let strategy = if let Some(version) = checks.iter().find_map(|check| match check {
+        StringCheck::Ip(version) => Some(*version),
+        _ => None,
+    }) {
+        match version {
+            Some(IpVersion::V4) => any::<std::net::Ipv4Addr>()
+                .prop_map(|ip| ip.to_string())
+                .boxed(),
+            Some(IpVersion::V6) => any::<std::net::Ipv6Addr>()
+                .prop_map(|ip| ip.to_string())
+                .boxed(),
+            None => any::<std::net::IpAddr>()
+                .prop_map(|ip| ip.to_string())
+                .boxed(),
+        }
+    } else if let Some(n) = checks.iter().find_map(|check| match check {
+        StringCheck::Length(n) => Some(*n),
+        _ => None,
+    }) {
+        proptest::string::string_regex(&format!("[a-zA-Z0-9]{{{n}}}"))
+            .expect("valid regex")
+            .boxed()
+    } else if let Some(regex) = pattern.and_then(|p| {
+        // anchors are implied when generating values
+        let p = p.strip_prefix('^').unwrap_or(&p);
+        let p = p.strip_suffix('$').unwrap_or(p);
+        proptest::string::string_regex(&js_regex(p)).ok()
+    }) {
+        regex.boxed()
+    } else {
+        proptest::string::string_regex(&format!("[ -~]{{{min},{max}}}"))
+            .expect("valid regex")
+            .boxed()
+    };
+
+    filtered(strategy.prop_map(Value::String).boxed(), refinements)
+}
+
+fn number(checks: &[NumberCheck], refinements: &[Refinement]) -> BoxedStrategy<Value> {
+    let int = checks.iter().any(|check| matches!(check, NumberCheck::Int));
+
+    let lower = checks
+        .iter()
+        .filter_map(|check| match check {
+            NumberCheck::Gte(n) => Some(*n),
+            NumberCheck::Nonnegative => Some(Numeric::Signed(0)),
+            _ => None,
+        })
+        .chain(
+            refinements
+                .iter()
+                .filter_map(|refinement| match refinement {
+                    Refinement::Min(n) => Some(*n),
+                    _ => None,
+                }),
+        );
+
+    let upper = checks
+        .iter()
+        .filter_map(|check| match check {
+            NumberCheck::Lte(n) => Some(*n),
+            _ => None,
+        })
+        .chain(
+            refinements
+                .iter()
+                .filter_map(|refinement| match refinement {
+                    Refinement::Max(n) => Some(*n),
+                    _ => None,
+                }),
+        );
+
+    if int {
+        // serde_json represents integers as i64 or u64
+        let lower = lower.map(as_i128).fold(i64::MIN as i128, i128::max);
+        let upper = upper.map(as_i128).fold(u64::MAX as i128, i128::min);
+
+        (lower..=upper.max(lower))
+            .prop_map(|n| match i64::try_from(n) {
+                Ok(n) => Value::from(n),
+                Err(_) => Value::from(n as u64),
+            })
+            .boxed()
+    } else {
+        let lower = lower.map(|n| n.as_f64()).fold(-1e9, f64::max);
+        let upper = upper.map(|n| n.as_f64()).fold(1e9, f64::min);
+
+        (lower..=upper.max(lower)).prop_map(Value::from).boxed()
+    }
+}
+
+fn as_i128(n: Numeric) -> i128 {
+    match n {
+        Numeric::Signed(n) => n,
+        Numeric::Unsigned(n) => i128::try_from(n).unwrap_or(i128::MAX),
+        Numeric::Float(n) => n as i128,
+    }
+}
+
+/// the length of strings and arrays allowed by the refinements
+fn length(refinements: &[Refinement]) -> (usize, usize) {
+    let min = refinements
+        .iter()
+        .filter_map(|refinement| match refinement {
+            Refinement::MinLength(n) => Some(*n),
+            Refinement::NonEmpty => Some(1),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+
+    let max = refinements
+        .iter()
+        .filter_map(|refinement| match refinement {
+            Refinement::MaxLength(n) => Some(*n),
+            _ => None,
+        })
+        .min()
+        .unwrap_or(min + 3);
+
+    (min, max.max(min))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ast::ZodField;
+    use crate::validate::validate_schema;
+    use proptest::strategy::ValueTree;
+
+    fn assert_valid(schema: ZodSchema) {
+        let mut runner = TestRunner::default();
+        let strategy = schema_strategy(&schema);
+
+        for _ in 0..64 {
+            let value = strategy.new_tree(&mut runner).unwrap().current();
+            if let Err(errors) = validate_schema(&schema, &value) {
+                panic!("{value} does not conform to {schema}: {errors}")
+            }
+        }
+    }
+
+    #[test]
+    fn primitives() {
+        assert_valid(u8::schema());
+        assert_valid(i64::schema());
+        assert_valid(u128::schema());
+        assert_valid(f32::schema());
+        assert_valid(char::schema());
+        assert_valid(std::net::IpAddr::schema());
+        assert_valid(<Option<bool>>::schema());
+    }
+
+    #[test]
+    fn containers() {
+        assert_valid(<Vec<(String, u8)>>::schema());
+        assert_valid(<std::collections::HashMap<u16, Vec<bool>>>::schema());
+        assert_valid(<Result<u8, String>>::schema());
+    }
+
+    #[test]
+    fn refinements() {
+        assert_valid(
+            ZodSchema::String(Vec::new())
+                .refine(Refinement::MinLength(3))
+                .refine(Refinement::MaxLength(5)),
+        );
+        assert_valid(ZodSchema::String(Vec::new()).refine(Refinement::Email));
+        assert_valid(ZodSchema::String(Vec::new()).refine(Refinement::Url));
+        assert_valid(
+            ZodSchema::String(Vec::new()).refine(Refinement::Regex(String::from("^[a-c]+$"))),
+        );
+        assert_valid(u8::schema().refine(Refinement::Min(Numeric::Signed(10))));
+        assert_valid(<Vec<bool>>::schema().refine(Refinement::NonEmpty));
+    }
+
+    #[test]
+    fn objects() {
+        assert_valid(ZodSchema::object(vec![
+            ZodField::new("a", ZodSchema::Boolean.optional()),
+            ZodField::new("b", ZodSchema::Boolean.nullable().optional()),
+            ZodField::new("c", ZodSchema::literal("c")),
+        ]));
+    }
+}
@@ -0,0 +1,1410 @@
+//! Typed representation of zod schemas and typescript types.
+//!
+//! [ZodType](crate::ZodType) implementations return these trees instead of pre-formatted strings.
+//! The [Display](std::fmt::Display) implementations render them into the code which ends up in the
+//! generated typescript.
+
+use std::fmt::{self, Display, Write};
+
+/// A zod schema expression, eg. `z.object({ a: z.string() })`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ZodSchema {
+    /// `z.string()` followed by its checks
+    String(Vec<StringCheck>),
+    /// `z.number()` followed by its checks
+    Number(Vec<NumberCheck>),
+    /// An integer which may exceed `Number.MAX_SAFE_INTEGER`, eg. `u64`. It is rendered
+    /// according to its [IntegerMode]
+    Integer {
+        min: Numeric,
+        max: Numeric,
+        mode: IntegerMode,
+    },
+    /// `z.boolean()`
+    Boolean,
+    /// `z.null()`
+    Null,
+    /// `z.unknown()`
+    Unknown,
+    /// `z.never()`, the schema of uninhabited types like empty enums
+    Never,
+    /// `z.literal(...)`
+    Literal(Literal),
+    /// The values of an enum with only unit variants. String values are rendered as
+    /// `z.enum(["A", "B", ...])`, numeric values as `z.union([z.literal(0), z.literal(1), ...])`
+    Enum(Vec<EnumMember>),
+    /// `z.nativeEnum(Ns.Name)`, a reference to a typescript `enum` declaration with the given
+    /// members
+    NativeEnum {
+        ns_name: &'static str,
+        name: &'static str,
+        members: Vec<EnumMember>,
+    },
+    /// `z.array(T)`
+    Array(Box<ZodSchema>),
+    /// `z.record(K, V)`
+    Record(Box<ZodSchema>, Box<ZodSchema>),
+    /// `z.tuple([T1, T2, ...])`
+    Tuple(Vec<ZodSchema>),
+    /// `z.object({ ... })`
+    Object(ZodObject),
+    /// `z.union([T1, T2, ...])`
+    Union(Vec<ZodSchema>),
+    /// `z.discriminatedUnion("tag", [T1, T2, ...])`
+    DiscriminatedUnion {
+        tag: String,
+        variants: Vec<ZodSchema>,
+    },
+    /// A reference to a namespace member: `z.lazy(() => Ns.Name)`. Generic members are
+    /// instantiated with their arguments: `z.lazy(() => Ns.Name(T1, T2))`
+    Lazy {
+        ns_name: &'static str,
+        name: &'static str,
+        args: Vec<ZodSchema>,
+    },
+    /// `T.optional()`
+    Optional(Box<ZodSchema>),
+    /// `T.nullable()`
+    Nullable(Box<ZodSchema>),
+    /// `T.nullish()`
+    Nullish(Box<ZodSchema>),
+    /// `T.default(value)`, the value is JSON and replaces a missing value while parsing
+    Defaulted(Box<ZodSchema>, String),
+    /// `T` followed by the refinements of a field, eg. `z.string().min(3).email()`
+    Refined(Box<ZodSchema>, Vec<Refinement>),
+    /// `T.describe("...")`, the documentation of an enum variant
+    Described(Box<ZodSchema>, String),
+    /// `T.brand<"Name">()`, a nominal type which is only assignable from values parsed by the schema
+    Branded(Box<ZodSchema>, String),
+    /// hand written zod code which is emitted verbatim
+    Raw(String),
+}
+
+impl ZodSchema {
+    pub fn literal(value: impl Into<Literal>) -> Self {
+        Self::Literal(value.into())
+    }
+
+    pub fn object(fields: Vec<ZodField>) -> Self {
+        Self::Object(ZodObject::new(fields))
+    }
+
+    /// `T.describe("...")`
+    pub fn describe(self, description: impl Into<String>) -> Self {
+        Self::Described(Box::new(self), description.into())
+    }
+
+    /// `T.brand<"Name">()`
+    pub fn brand(self, name: impl Into<String>) -> Self {
+        Self::Branded(Box::new(self), name.into())
+    }
+
+    /// the schema without its description
+    pub fn undescribed(&self) -> &Self {
+        match self {
+            Self::Described(inner, _) => inner.undescribed(),
+            other => other,
+        }
+    }
+
+    /// `z.union([T, other])`, the variants of a union are extended
+    pub fn or(self, other: ZodSchema) -> Self {
+        match self {
+            Self::Union(mut variants) => {
+                variants.push(other);
+                Self::Union(variants)
+            }
+            this => Self::Union(vec![this, other]),
+        }
+    }
+
+    /// `T.optional()`, a nullable schema becomes `T.nullish()`
+    pub fn optional(self) -> Self {
+        match self {
+            Self::Nullable(inner) => Self::Nullish(inner),
+            Self::Optional(_) | Self::Nullish(_) => self,
+            other => Self::Optional(Box::new(other)),
+        }
+    }
+
+    pub fn nullable(self) -> Self {
+        match self {
+            Self::Optional(inner) => Self::Nullish(inner),
+            Self::Nullable(_) | Self::Nullish(_) => self,
+            other => Self::Nullable(Box::new(other)),
+        }
+    }
+
+    /// `T.default(value)`, `value` is the JSON of the default value
+    pub fn with_default(self, value: impl Into<String>) -> Self {
+        Self::Defaulted(Box::new(self), value.into())
+    }
+
+    /// add a refinement to the schema. Refinements apply to the value, so they are added inside of
+    /// `.optional()`, `.nullable()`, `.nullish()` and `.default(..)`.
+    pub fn refine(self, refinement: Refinement) -> Self {
+        match self {
+            Self::Defaulted(inner, value) => {
+                Self::Defaulted(Box::new(inner.refine(refinement)), value)
+            }
+            Self::Optional(inner) => Self::Optional(Box::new(inner.refine(refinement))),
+            Self::Nullable(inner) => Self::Nullable(Box::new(inner.refine(refinement))),
+            Self::Nullish(inner) => Self::Nullish(Box::new(inner.refine(refinement))),
+            Self::Refined(inner, mut refinements) => {
+                refinements.push(refinement);
+                Self::Refined(inner, refinements)
+            }
+            other => Self::Refined(Box::new(other), vec![refinement]),
+        }
+    }
+
+    /// represent the wide integers of the schema with `mode`. Integers which already use a mode
+    /// other than [IntegerMode::Number] are left unchanged. References to other members are not
+    /// followed, only their type arguments are changed.
+    pub fn with_integer_mode(self, mode: IntegerMode) -> Self {
+        let map = |schemas: Vec<ZodSchema>| {
+            schemas
+                .into_iter()
+                .map(|schema| schema.with_integer_mode(mode))
+                .collect()
+        };
+
+        let map_box = |schema: Box<ZodSchema>| Box::new(schema.with_integer_mode(mode));
+
+        match self {
+            Self::Integer {
+                min,
+                max,
+                mode: IntegerMode::Number,
+            } => Self::Integer { min, max, mode },
+            Self::Array(inner) => Self::Array(map_box(inner)),
+            Self::Record(key, value) => Self::Record(key, map_box(value)),
+            Self::Tuple(items) => Self::Tuple(map(items)),
+            Self::Object(obj) => Self::Object(ZodObject {
+                fields: obj
+                    .fields
+                    .into_iter()
+                    .map(|field| ZodField {
+                        schema: field.schema.with_integer_mode(mode),
+                        ..field
+                    })
+                    .collect(),
+                extends: map(obj.extends),
+                catchall: obj.catchall.map(map_box),
+                ..obj
+            }),
+            Self::Union(variants) => Self::Union(map(variants)),
+            Self::DiscriminatedUnion { tag, variants } => Self::DiscriminatedUnion {
+                tag,
+                variants: map(variants),
+            },
+            Self::Lazy {
+                ns_name,
+                name,
+                args,
+            } => Self::Lazy {
+                ns_name,
+                name,
+                args: map(args),
+            },
+            Self::Optional(inner) => Self::Optional(map_box(inner)),
+            Self::Nullable(inner) => Self::Nullable(map_box(inner)),
+            Self::Nullish(inner) => Self::Nullish(map_box(inner)),
+            Self::Defaulted(inner, value) => Self::Defaulted(map_box(inner), value),
+            Self::Refined(inner, refinements) => Self::Refined(map_box(inner), refinements),
+            Self::Described(inner, description) => Self::Described(map_box(inner), description),
+            Self::Branded(inner, name) => Self::Branded(map_box(inner), name),
+            other => other,
+        }
+    }
+
+    /// change how the `None` of an `Option<T>` is represented. Non nullable schemas are returned
+    /// unchanged.
+    pub fn with_option_mode(self, mode: OptionMode) -> Self {
+        match self {
+            Self::Nullable(inner) => match mode {
+                OptionMode::Nullable => Self::Nullable(inner),
+                OptionMode::Optional => Self::Optional(inner),
+                OptionMode::Nullish => Self::Nullish(inner),
+            },
+            other => other,
+        }
+    }
+}
+
+/// A variant of an enum with only unit variants and its serialized value
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumMember {
+    pub name: String,
+    pub value: Literal,
+    /// emitted as JSDoc of the generated enum member
+    pub docs: Option<String>,
+}
+
+impl EnumMember {
+    pub fn new(name: impl Into<String>, value: impl Into<Literal>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            docs: None,
+        }
+    }
+
+    pub fn with_docs(mut self, docs: impl Into<String>) -> Self {
+        self.docs = Some(docs.into());
+        self
+    }
+}
+
+/// A validation rule declared on a field, eg. `#[zod(min_length = 3)]`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Refinement {
+    /// `.min(n)` on strings and arrays
+    MinLength(usize),
+    /// `.max(n)` on strings and arrays
+    MaxLength(usize),
+    /// `.min(n)` on numbers
+    Min(Numeric),
+    /// `.max(n)` on numbers
+    Max(Numeric),
+    /// `.regex(/pattern/)`
+    Regex(String),
+    /// `.email()`
+    Email,
+    /// `.url()`
+    Url,
+    /// `.min(1)` on strings and arrays
+    NonEmpty,
+}
+
+/// The representation of integers which exceed `Number.MAX_SAFE_INTEGER`.
+///
+/// Javascript numbers lose precision above 2^53. Wide integers can therefore be represented as a
+/// `bigint` or as a decimal string instead. Fields with either mode are sent as strings, see
+/// [bigint::as_string](crate::bigint::as_string).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntegerMode {
+    /// `number`, precision is lost above `Number.MAX_SAFE_INTEGER`
+    #[default]
+    Number,
+    /// `bigint`, parsed from a decimal string or a number with `z.coerce.bigint()`
+    BigInt,
+    /// a decimal `string`
+    String,
+}
+
+/// The representation of `None`.
+///
+/// serde serializes `None` as `null`, unless the field is skipped with
+/// `#[serde(skip_serializing_if = "Option::is_none")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionMode {
+    /// `T | null`
+    Nullable,
+    /// `T | undefined`, the field may be missing
+    Optional,
+    /// `T | null | undefined`
+    Nullish,
+}
+
+/// How a `z.object({ ... })` treats keys which are not part of its shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnknownKeys {
+    /// unknown keys are removed from the parsed value
+    #[default]
+    Strip,
+    /// `.passthrough()`, unknown keys are kept
+    Passthrough,
+    /// `.strict()`, unknown keys are rejected
+    Strict,
+}
+
+/// The shape of a `z.object({ ... })`. Flattened members are appended with `.extend(...)`, see
+/// [ZodObject::optional_extension] for flattened members which may be missing
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ZodObject {
+    pub fields: Vec<ZodField>,
+    pub extends: Vec<ZodSchema>,
+    pub unknown_keys: UnknownKeys,
+    /// `.catchall(...)`, the schema of all unknown keys. It takes precedence over `unknown_keys`.
+    pub catchall: Option<Box<ZodSchema>>,
+}
+
+impl ZodObject {
+    pub fn new(fields: Vec<ZodField>) -> Self {
+        Self {
+            fields,
+            extends: Vec::new(),
+            unknown_keys: UnknownKeys::default(),
+            catchall: None,
+        }
+    }
+
+    /// Merge a flattened member into the object. A flattened map collects all remaining keys and
+    /// becomes the catchall of the object.
+    pub fn extend(mut self, other: ZodSchema) -> Self {
+        match other {
+            ZodSchema::Record(_, value) => self.catchall = Some(value),
+            other => self.extends.push(other),
+        }
+        self
+    }
+
+    /// The inner schema of a flattened `Option<T>`. Serde omits the fields of `None` and falls back
+    /// to `None` if they don't match `T`, so the object is intersected with `z.union([T,
+    /// z.object({})])` instead of being extended by a nullable schema.
+    pub fn optional_extension(ext: &ZodSchema) -> Option<&ZodSchema> {
+        match ext.undescribed() {
+            ZodSchema::Nullable(inner) | ZodSchema::Optional(inner) | ZodSchema::Nullish(inner) => {
+                Some(inner)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn with_unknown_keys(mut self, unknown_keys: UnknownKeys) -> Self {
+        self.unknown_keys = unknown_keys;
+        self
+    }
+
+    /// the alternative keys of the fields and the keys they are renamed to
+    pub fn aliases(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields.iter().flat_map(|field| {
+            field
+                .aliases
+                .iter()
+                .map(|alias| (alias.as_str(), field.name.as_str()))
+        })
+    }
+}
+
+impl From<ZodObject> for ZodSchema {
+    fn from(value: ZodObject) -> Self {
+        Self::Object(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZodField {
+    pub name: String,
+    pub schema: ZodSchema,
+    /// alternative keys which are accepted instead of `name`, see `#[serde(alias = "..")]`
+    pub aliases: Vec<String>,
+    /// `.describe("...")`, the documentation of the field
+    pub description: Option<String>,
+}
+
+impl ZodField {
+    pub fn new(name: impl Into<String>, schema: ZodSchema) -> Self {
+        Self {
+            name: name.into(),
+            schema,
+            aliases: Vec::new(),
+            description: None,
+        }
+    }
+
+    pub fn describe(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_aliases(mut self, aliases: &[&str]) -> Self {
+        self.aliases = aliases.iter().map(|alias| String::from(*alias)).collect();
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StringCheck {
+    /// `.length(n)`
+    Length(usize),
+    /// `.ip()` optionally restricted to one version
+    Ip(Option<IpVersion>),
+    /// `.regex(/pattern/)`
+    Regex(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpVersion {
+    V4,
+    V6,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumberCheck {
+    /// `.finite()`
+    Finite,
+    /// `.int()`
+    Int,
+    /// `.nonnegative()`
+    Nonnegative,
+    /// `.gte(n)`
+    Gte(Numeric),
+    /// `.lte(n)`
+    Lte(Numeric),
+}
+
+/// A number as it appears in generated code. Wide integers are kept exact.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Numeric {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+/// the checks of `z.number()` for an integer in the range `min..=max`
+pub(crate) fn integer_checks(min: Numeric, max: Numeric) -> Vec<NumberCheck> {
+    if min == Numeric::Unsigned(0) {
+        vec![
+            NumberCheck::Finite,
+            NumberCheck::Int,
+            NumberCheck::Nonnegative,
+            NumberCheck::Lte(max),
+        ]
+    } else {
+        vec![
+            NumberCheck::Finite,
+            NumberCheck::Int,
+            NumberCheck::Lte(max),
+            NumberCheck::Gte(min),
+        ]
+    }
+}
+
+/// the pattern of a decimal string which represents an integer, negative only if `min` is
+pub(crate) fn integer_pattern(min: Numeric) -> &'static str {
+    match min {
+        Numeric::Signed(n) if n < 0 => r"^-?\d+$",
+        _ => r"^\d+$",
+    }
+}
+
+impl Numeric {
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            Self::Signed(n) => n as f64,
+            Self::Unsigned(n) => n as f64,
+            Self::Float(n) => n,
+        }
+    }
+}
+
+macro_rules! impl_numeric_from {
+    ($variant:ident as $as:ty: $($t:ty),*) => {
+        $(impl From<$t> for Numeric {
+            fn from(value: $t) -> Self {
+                Self::$variant(value as $as)
+            }
+        })*
+    };
+}
+
+impl_numeric_from!(Signed as i128: i8, i16, i32, i64, i128, isize);
+impl_numeric_from!(Unsigned as u128: u8, u16, u32, u64, u128, usize);
+impl_numeric_from!(Float as f64: f32, f64);
+
+impl Display for Numeric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signed(n) => write!(f, "{n}"),
+            Self::Unsigned(n) => write!(f, "{n}"),
+            Self::Float(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+/// A literal value shared by zod schemas and typescript types
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    String(String),
+    /// the discriminant of a `#[repr(u8)]` like enum
+    Int(i128),
+}
+
+impl From<&str> for Literal {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for Literal {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<i128> for Literal {
+    fn from(value: i128) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl Display for Literal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(s) => f.write_str(&string_literal(s)),
+            Self::Int(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+/// A typescript type expression, eg. `{ a: string } | null`
+#[derive(Debug, Clone, PartialEq)]
+pub enum TsType {
+    String,
+    Number,
+    /// `number`, `bigint` or `string`, see [ZodSchema::Integer]
+    Integer(IntegerMode),
+    Boolean,
+    Null,
+    Undefined,
+    Unknown,
+    Never,
+    Literal(Literal),
+    /// `Array<T>`
+    Array(Box<TsType>),
+    /// `Record<K, V>`
+    Record(Box<TsType>, Box<TsType>),
+    /// `Partial<T>`
+    Partial(Box<TsType>),
+    /// `[T1, T2, ...]`
+    Tuple(Vec<TsType>),
+    /// `{ a: T1, b?: T2 }`
+    Object(Vec<TsField>),
+    /// `T1 | T2 | ...`
+    Union(Vec<TsType>),
+    /// `T1 & T2 & ...`
+    Intersection(Vec<TsType>),
+    /// A reference to a namespace member: `Ns.Name` or `Ns.Name<T1, T2>` for generic members
+    Ref {
+        ns_name: &'static str,
+        name: &'static str,
+        args: Vec<TsType>,
+    },
+    /// `/** docs */ T`, the documentation of an enum variant
+    Documented(Box<TsType>, String),
+    /// hand written typescript which is emitted verbatim
+    Raw(String),
+}
+
+impl TsType {
+    pub fn literal(value: impl Into<Literal>) -> Self {
+        Self::Literal(value.into())
+    }
+
+    /// `/** docs */ T`
+    pub fn with_docs(self, docs: impl Into<String>) -> Self {
+        Self::Documented(Box::new(self), docs.into())
+    }
+
+    /// `T & { __brand: "Name" }`, see [ZodSchema::brand]
+    pub fn branded(self, name: impl Into<String>) -> Self {
+        let brand = Self::Object(vec![TsField::new("__brand", Self::literal(name.into()))]);
+        Self::Intersection(vec![self, brand])
+    }
+
+    /// `T | other`
+    pub fn or(self, other: TsType) -> Self {
+        self.with_member(other)
+    }
+
+    /// `T & {}` accepts every `T` but keeps the autocompletion of literals in a union with it
+    pub fn open(self) -> Self {
+        Self::Intersection(vec![self, Self::Object(Vec::new())])
+    }
+
+    /// `T | undefined`
+    pub fn optional(self) -> Self {
+        self.with_member(Self::Undefined)
+    }
+
+    /// `T | null`
+    pub fn nullable(self) -> Self {
+        self.with_member(Self::Null)
+    }
+
+    /// The fields a flattened member adds to an object. A missing value like `None` of a
+    /// flattened `Option<T>` adds no fields, it is therefore `{}` instead of `null`.
+    pub fn flattened(self) -> Self {
+        match self {
+            Self::Union(variants) => Self::Union(
+                variants
+                    .into_iter()
+                    .map(|variant| match variant {
+                        Self::Null | Self::Undefined => Self::Object(Vec::new()),
+                        other => other,
+                    })
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
+    /// see [ZodSchema::with_integer_mode]
+    pub fn with_integer_mode(self, mode: IntegerMode) -> Self {
+        let map = |types: Vec<TsType>| {
+            types
+                .into_iter()
+                .map(|ty| ty.with_integer_mode(mode))
+                .collect()
+        };
+
+        let map_box = |ty: Box<TsType>| Box::new(ty.with_integer_mode(mode));
+
+        match self {
+            Self::Integer(IntegerMode::Number) => Self::Integer(mode),
+            Self::Array(inner) => Self::Array(map_box(inner)),
+            Self::Record(key, value) => Self::Record(key, map_box(value)),
+            Self::Partial(inner) => Self::Partial(map_box(inner)),
+            Self::Tuple(items) => Self::Tuple(map(items)),
+            Self::Object(fields) => Self::Object(
+                fields
+                    .into_iter()
+                    .map(|field| TsField {
+                        ty: field.ty.with_integer_mode(mode),
+                        ..field
+                    })
+                    .collect(),
+            ),
+            Self::Union(variants) => Self::Union(map(variants)),
+            Self::Intersection(items) => Self::Intersection(map(items)),
+            Self::Documented(inner, docs) => Self::Documented(map_box(inner), docs),
+            Self::Ref {
+                ns_name,
+                name,
+                args,
+            } => Self::Ref {
+                ns_name,
+                name,
+                args: map(args),
+            },
+            other => other,
+        }
+    }
+
+    /// see [ZodSchema::with_option_mode]
+    pub fn with_option_mode(self, mode: OptionMode) -> Self {
+        match (self, mode) {
+            (Self::Union(mut members), OptionMode::Optional) if members.contains(&Self::Null) => {
+                members.retain(|m| m != &Self::Null);
+                Self::Union(members).optional()
+            }
+            (Self::Union(members), OptionMode::Nullish) if members.contains(&Self::Null) => {
+                Self::Union(members).optional()
+            }
+            (other, _) => other,
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        matches!(self, Self::Union(members) if members.contains(&Self::Undefined))
+    }
+
+    fn with_member(self, member: TsType) -> Self {
+        match self {
+            Self::Union(mut members) => {
+                if !members.contains(&member) {
+                    members.push(member);
+                }
+                Self::Union(members)
+            }
+            other => Self::Union(vec![other, member]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TsField {
+    pub name: String,
+    pub optional: bool,
+    pub ty: TsType,
+    /// emitted as JSDoc of the field
+    pub docs: Option<String>,
+}
+
+impl TsField {
+    pub fn new(name: impl Into<String>, ty: TsType) -> Self {
+        Self {
+            name: name.into(),
+            optional: false,
+            ty,
+            docs: None,
+        }
+    }
+
+    pub fn with_docs(mut self, docs: impl Into<String>) -> Self {
+        self.docs = Some(docs.into());
+        self
+    }
+
+    /// mark the field as optional, `name?: T | undefined`
+    pub fn optional(self) -> Self {
+        Self {
+            optional: true,
+            ty: self.ty.optional(),
+            ..self
+        }
+    }
+
+    /// see [ZodSchema::with_option_mode], the field becomes optional if `None` is represented
+    /// by a missing field
+    pub fn with_option_mode(self, mode: OptionMode) -> Self {
+        let ty = self.ty.with_option_mode(mode);
+
+        Self {
+            optional: self.optional || ty.is_optional(),
+            ty,
+            ..self
+        }
+    }
+}
+
+/// A double quoted javascript string
+pub(crate) fn string_literal(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{2028}' => out.push_str("\\u2028"),
+            '\u{2029}' => out.push_str("\\u2029"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// A key of an object literal or an object type. Keys which are not plain identifiers, like
+/// `content-type` from `#[serde(rename_all = "kebab-case")]`, are quoted.
+pub(crate) fn object_key(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+    if is_identifier {
+        name.to_owned()
+    } else {
+        string_literal(name)
+    }
+}
+
+/// The value parameter of a generic factory which takes the schema of the type parameter `name`:
+/// `<T extends z.ZodTypeAny>(schemaT: T) => ...`
+pub fn schema_param(name: &str) -> String {
+    format!("schema{name}")
+}
+
+/// A JSDoc comment. `*/` in the docs would end the comment early and is escaped.
+pub fn jsdoc(docs: &str) -> String {
+    let docs = docs.replace("*/", "*\\/");
+    let mut lines = docs.lines();
+
+    match (lines.next(), lines.next()) {
+        (None, _) => String::from("/** */"),
+        (Some(line), None) => format!("/** {line} */"),
+        _ => {
+            let lines = docs
+                .lines()
+                .map(|line| format!(" * {line}\n"))
+                .collect::<String>();
+            format!("/**\n{lines} */")
+        }
+    }
+}
+
+struct Joined<'a, T>(&'a [T], &'static str);
+
+impl<'a, T: Display> Display for Joined<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, item) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.1)?;
+            }
+            write!(f, "{item}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ZodSchema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(checks) => {
+                f.write_str("z.string()")?;
+                for check in checks {
+                    match check {
+                        StringCheck::Length(n) => write!(f, ".length({n})")?,
+                        StringCheck::Ip(None) => f.write_str(".ip()")?,
+                        StringCheck::Ip(Some(IpVersion::V4)) => {
+                            f.write_str(".ip({ version: \"v4\" })")?
+                        }
+                        StringCheck::Ip(Some(IpVersion::V6)) => {
+                            f.write_str(".ip({ version: \"v6\" })")?
+                        }
+                        StringCheck::Regex(pattern) => {
+                            write!(f, ".regex({})", RegexLiteral(pattern))?
+                        }
+                    }
+                }
+                Ok(())
+            }
+            Self::Number(checks) => {
+                f.write_str("z.number()")?;
+                for check in checks {
+                    match check {
+                        NumberCheck::Finite => f.write_str(".finite()")?,
+                        NumberCheck::Int => f.write_str(".int()")?,
+                        NumberCheck::Nonnegative => f.write_str(".nonnegative()")?,
+                        NumberCheck::Gte(n) => write!(f, ".gte({n})")?,
+                        NumberCheck::Lte(n) => write!(f, ".lte({n})")?,
+                    }
+                }
+                Ok(())
+            }
+            Self::Integer { min, max, mode } => match mode {
+                IntegerMode::Number => {
+                    write!(f, "{}", Self::Number(integer_checks(*min, *max)))
+                }
+                IntegerMode::BigInt => write!(f, "z.coerce.bigint().gte({min}n).lte({max}n)"),
+                IntegerMode::String => write!(
+                    f,
+                    "{}",
+                    Self::String(vec![StringCheck::Regex(String::from(integer_pattern(
+                        *min
+                    )))])
+                ),
+            },
+            Self::Boolean => f.write_str("z.boolean()"),
+            Self::Null => f.write_str("z.null()"),
+            Self::Never => f.write_str("z.never()"),
+            Self::Unknown => f.write_str("z.unknown()"),
+            Self::Literal(lit) => write!(f, "z.literal({lit})"),
+            Self::Enum(members) => {
+                let values = members.iter().map(|m| &m.value).collect::<Vec<_>>();
+
+                if values.iter().all(|v| matches!(v, Literal::String(_))) {
+                    write!(f, "z.enum([{}])", Joined(&values, ", "))
+                } else {
+                    let literals = values
+                        .into_iter()
+                        .map(|v| Self::Literal(v.clone()))
+                        .collect::<Vec<_>>();
+
+                    match literals.as_slice() {
+                        [single] => write!(f, "{single}"),
+                        _ => write!(f, "{}", Self::Union(literals)),
+                    }
+                }
+            }
+            Self::NativeEnum { ns_name, name, .. } => write!(f, "z.nativeEnum({ns_name}.{name})"),
+            Self::Array(inner) => write!(f, "z.array({inner})"),
+            Self::Record(k, v) => write!(f, "z.record({k}, {v})"),
+            Self::Tuple(items) => write!(f, "z.tuple([{}])", Joined(items, ", ")),
+            Self::Object(obj) => {
+                let aliases = obj
+                    .aliases()
+                    .map(|(alias, name)| {
+                        format!("[{}, {}]", string_literal(alias), string_literal(name))
+                    })
+                    .collect::<Vec<_>>();
+
+                // aliased keys are renamed before the object is parsed
+                if !aliases.is_empty() {
+                    write!(
+                        f,
+                        "z.preprocess((value) => value !== null && typeof value === \"object\" && !Array.isArray(value) ? Object.fromEntries(Object.entries(value).map(([key, v]) => [new Map([{}]).get(key) ?? key, v])) : value, ",
+                        aliases.join(", ")
+                    )?;
+                }
+
+                if obj.fields.is_empty() {
+                    f.write_str("z.object({})")?;
+                } else {
+                    write!(f, "z.object({{ {} }})", Joined(&obj.fields, ", "))?;
+                }
+                let (optional_extends, extends) = obj
+                    .extends
+                    .iter()
+                    .partition::<Vec<_>, _>(|ext| ZodObject::optional_extension(ext).is_some());
+
+                for ext in extends {
+                    write!(f, ".extend({ext})")?;
+                }
+                match (&obj.catchall, obj.unknown_keys) {
+                    (Some(catchall), _) => write!(f, ".catchall({catchall})")?,
+                    (None, UnknownKeys::Strip) => {}
+                    (None, UnknownKeys::Passthrough) => f.write_str(".passthrough()")?,
+                    (None, UnknownKeys::Strict) => f.write_str(".strict()")?,
+                }
+                // an intersection is no object anymore, it comes after the object methods
+                for ext in optional_extends.into_iter().filter_map(ZodObject::optional_extension) {
+                    write!(f, ".and(z.union([{ext}, z.object({{}})]))")?;
+                }
+
+                if !aliases.is_empty() {
+                    f.write_str(")")?;
+                }
+
+                Ok(())
+            }
+            Self::Union(variants) => write!(f, "z.union([{}])", Joined(variants, ", ")),
+            // `z.discriminatedUnion` only accepts plain objects
+            Self::DiscriminatedUnion { variants, .. }
+                if variants.iter().any(
+                    |variant| matches!(variant.undescribed(), Self::Object(obj) if obj.aliases().next().is_some()),
+                ) =>
+            {
+                write!(f, "z.union([{}])", Joined(variants, ", "))
+            }
+            Self::DiscriminatedUnion { tag, variants } => write!(
+                f,
+                "z.discriminatedUnion({}, [{}])",
+                string_literal(tag),
+                Joined(variants, ", ")
+            ),
+            Self::Lazy {
+                ns_name,
+                name,
+                args,
+            } => {
+                if args.is_empty() {
+                    write!(f, "z.lazy(() => {ns_name}.{name})")
+                } else {
+                    write!(f, "z.lazy(() => {ns_name}.{name}({}))", Joined(args, ", "))
+                }
+            }
+            Self::Optional(inner) => write!(f, "{inner}.optional()"),
+            Self::Defaulted(inner, value) => write!(f, "{inner}.default({value})"),
+            Self::Nullable(inner) => write!(f, "{inner}.nullable()"),
+            Self::Nullish(inner) => write!(f, "{inner}.nullish()"),
+            Self::Refined(inner, refinements) => {
+                write!(f, "{inner}")?;
+                for refinement in refinements {
+                    write!(f, "{refinement}")?;
+                }
+                Ok(())
+            }
+            Self::Described(inner, description) => {
+                write!(f, "{inner}.describe({})", string_literal(description))
+            }
+            Self::Branded(inner, name) => write!(f, "{inner}.brand<{}>()", string_literal(name)),
+            Self::Raw(code) => f.write_str(code),
+        }
+    }
+}
+
+impl Display for Refinement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MinLength(n) => write!(f, ".min({n})"),
+            Self::MaxLength(n) => write!(f, ".max({n})"),
+            Self::Min(n) => write!(f, ".min({n})"),
+            Self::Max(n) => write!(f, ".max({n})"),
+            Self::Regex(pattern) => write!(f, ".regex({})", RegexLiteral(pattern)),
+            Self::Email => f.write_str(".email()"),
+            Self::Url => f.write_str(".url()"),
+            Self::NonEmpty => f.write_str(".min(1)"),
+        }
+    }
+}
+
+/// A javascript regex literal, unescaped slashes would terminate the literal
+struct RegexLiteral<'a>(&'a str);
+
+impl<'a> Display for RegexLiteral<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('/')?;
+        let mut escaped = false;
+        for c in self.0.chars() {
+            if c == '/' && !escaped {
+                f.write_char('\\')?;
+            }
+            escaped = c == '\\' && !escaped;
+            f.write_char(c)?;
+        }
+        f.write_char('/')
+    }
+}
+
+impl Display for ZodField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", object_key(&self.name), self.schema)?;
+        if let Some(description) = &self.description {
+            write!(f, ".describe({})", string_literal(description))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for TsType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String => f.write_str("string"),
+            Self::Number => f.write_str("number"),
+            Self::Integer(IntegerMode::Number) => f.write_str("number"),
+            Self::Integer(IntegerMode::BigInt) => f.write_str("bigint"),
+            Self::Integer(IntegerMode::String) => f.write_str("string"),
+            Self::Boolean => f.write_str("boolean"),
+            Self::Null => f.write_str("null"),
+            Self::Never => f.write_str("never"),
+            Self::Undefined => f.write_str("undefined"),
+            Self::Unknown => f.write_str("unknown"),
+            Self::Literal(lit) => write!(f, "{lit}"),
+            Self::Array(inner) => write!(f, "Array<{inner}>"),
+            Self::Record(k, v) => write!(f, "Record<{k}, {v}>"),
+            Self::Partial(inner) => write!(f, "Partial<{inner}>"),
+            Self::Tuple(items) => write!(f, "[{}]", Joined(items, ", ")),
+            Self::Object(fields) if fields.is_empty() => f.write_str("{}"),
+            Self::Object(fields) => write!(f, "{{ {} }}", Joined(fields, ", ")),
+            Self::Union(variants) => {
+                for (i, variant) in variants.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" | ")?;
+                    }
+                    match variant {
+                        Self::Intersection(_) => write!(f, "({variant})")?,
+                        _ => write!(f, "{variant}")?,
+                    }
+                }
+                Ok(())
+            }
+            Self::Intersection(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" & ")?;
+                    }
+                    match item {
+                        Self::Union(_) => write!(f, "({item})")?,
+                        _ => write!(f, "{item}")?,
+                    }
+                }
+                Ok(())
+            }
+            Self::Ref {
+                ns_name,
+                name,
+                args,
+            } => {
+                write!(f, "{ns_name}.{name}")?;
+                if !args.is_empty() {
+                    write!(f, "<{}>", Joined(args, ", "))?;
+                }
+                Ok(())
+            }
+            Self::Documented(inner, docs) => write!(f, "{} {inner}", jsdoc(docs)),
+            Self::Raw(code) => f.write_str(code),
+        }
+    }
+}
+
+impl Display for TsField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(docs) = &self.docs {
+            write!(f, "{} ", jsdoc(docs))?;
+        }
+        f.write_str(&object_key(&self.name))?;
+        if self.optional {
+            f.write_char('?')?;
+        }
+        write!(f, ": {}", self.ty)
+    }
+}
+
+macro_rules! impl_str_eq {
+    ($($t:ty),*) => {
+        $(
+            impl PartialEq<&str> for $t {
+                fn eq(&self, other: &&str) -> bool {
+                    self.eq(*other)
+                }
+            }
+
+            impl PartialEq<str> for $t {
+                fn eq(&self, other: &str) -> bool {
+                    let rendered = self.to_string();
+                    rendered == other
+                }
+            }
+
+            impl PartialEq<String> for $t {
+                fn eq(&self, other: &String) -> bool {
+                    self.eq(other.as_str())
+                }
+            }
+        )*
+    };
+}
+
+impl_str_eq!(ZodSchema, TsType);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render_object() {
+        let schema = ZodObject::new(vec![
+            ZodField::new("a", ZodSchema::String(Vec::new())),
+            ZodField::new("b", ZodSchema::Number(vec![NumberCheck::Int]).optional()),
+        ])
+        .extend(ZodSchema::Lazy {
+            ns_name: "Ns",
+            name: "Other",
+            args: Vec::new(),
+        });
+
+        assert_eq!(
+            ZodSchema::from(schema),
+            "z.object({ a: z.string(), b: z.number().int().optional() }).extend(z.lazy(() => Ns.Other))"
+        );
+    }
+
+    #[test]
+    fn render_unknown_keys() {
+        let fields = || vec![ZodField::new("a", ZodSchema::String(Vec::new()))];
+
+        assert_eq!(
+            ZodSchema::from(ZodObject::new(fields()).with_unknown_keys(UnknownKeys::Strict)),
+            "z.object({ a: z.string() }).strict()"
+        );
+        assert_eq!(
+            ZodSchema::from(ZodObject::new(fields()).with_unknown_keys(UnknownKeys::Passthrough)),
+            "z.object({ a: z.string() }).passthrough()"
+        );
+        assert_eq!(
+            ZodSchema::from(ZodObject::new(fields()).extend(ZodSchema::Record(
+                Box::new(ZodSchema::String(Vec::new())),
+                Box::new(ZodSchema::Boolean)
+            ))),
+            "z.object({ a: z.string() }).catchall(z.boolean())"
+        );
+    }
+
+    #[test]
+    fn render_ts_intersection() {
+        let ty = TsType::Intersection(vec![
+            TsType::Object(vec![TsField::new("a", TsType::String).optional()]),
+            TsType::Ref {
+                ns_name: "Ns",
+                name: "Other",
+                args: Vec::new(),
+            }
+            .optional(),
+        ]);
+
+        assert_eq!(ty, "{ a?: string | undefined } & (Ns.Other | undefined)");
+    }
+
+    #[test]
+    fn render_open_unions() {
+        let ty = TsType::literal("A")
+            .or(TsType::literal("B"))
+            .or(TsType::String.open());
+
+        assert_eq!(ty, "\"A\" | \"B\" | (string & {})");
+        assert_eq!(
+            ZodSchema::Enum(vec![EnumMember::new("A", "A")]).or(ZodSchema::String(Vec::new())),
+            "z.union([z.enum([\"A\"]), z.string()])"
+        );
+    }
+
+    #[test]
+    fn render_docs() {
+        let field = TsField::new("a", TsType::String).with_docs("one */\ntwo");
+        let schema = ZodSchema::Boolean.describe("a \"quoted\"\\path");
+
+        assert_eq!(field.to_string(), "/**\n * one *\\/\n * two\n */ a: string");
+        assert_eq!(schema, "z.boolean().describe(\"a \\\"quoted\\\"\\\\path\")");
+        assert_eq!(
+            ZodField::new("a", ZodSchema::Null)
+                .describe("b")
+                .to_string(),
+            "a: z.null().describe(\"b\")"
+        );
+    }
+
+    #[test]
+    fn render_keys() {
+        let fields = ["a", "_b", "$c", "content-type", "1st", "名前", "class"]
+            .into_iter()
+            .map(|name| ZodField::new(name, ZodSchema::Null))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            ZodSchema::from(ZodObject::new(fields)),
+            "z.object({ a: z.null(), _b: z.null(), $c: z.null(), \"content-type\": z.null(), \"1st\": z.null(), \"名前\": z.null(), class: z.null() })"
+        );
+        assert_eq!(
+            TsType::Object(vec![TsField::new("content-type", TsType::Null).optional()]),
+            "{ \"content-type\"?: null | undefined }"
+        );
+    }
+
+    #[test]
+    fn render_string_literals() {
+        assert_eq!(
+            ZodSchema::literal("a \"b\"\\\n"),
+            "z.literal(\"a \\\"b\\\"\\\\\\n\")"
+        );
+        assert_eq!(TsType::literal("\u{2028}"), "\"\\u2028\"");
+        assert_eq!(
+            ZodSchema::DiscriminatedUnion {
+                tag: String::from("ty\"pe"),
+                variants: Vec::new()
+            },
+            "z.discriminatedUnion(\"ty\\\"pe\", [])"
+        );
+    }
+
+    #[test]
+    fn render_generic_refs() {
+        let schema = ZodSchema::Lazy {
+            ns_name: "Ns",
+            name: "Page",
+            args: vec![ZodSchema::String(Vec::new()), ZodSchema::Boolean],
+        };
+
+        let ty = TsType::Ref {
+            ns_name: "Ns",
+            name: "Page",
+            args: vec![TsType::String, TsType::Boolean],
+        };
+
+        assert_eq!(schema, "z.lazy(() => Ns.Page(z.string(), z.boolean()))");
+        assert_eq!(ty, "Ns.Page<string, boolean>");
+    }
+
+    #[test]
+    fn render_wide_integers() {
+        assert_eq!(
+            ZodSchema::Number(vec![NumberCheck::Lte(u128::MAX.into())]),
+            "z.number().lte(340282366920938463463374607431768211455)"
+        );
+    }
+
+    #[test]
+    fn integer_modes() {
+        let schema = ZodSchema::Array(Box::new(ZodSchema::Integer {
+            min: 0u64.into(),
+            max: u64::MAX.into(),
+            mode: IntegerMode::Number,
+        }));
+
+        let ty = TsType::Array(Box::new(TsType::Integer(IntegerMode::Number)));
+
+        assert_eq!(
+            schema.clone(),
+            "z.array(z.number().finite().int().nonnegative().lte(18446744073709551615))"
+        );
+        assert_eq!(
+            schema.clone().with_integer_mode(IntegerMode::BigInt),
+            "z.array(z.coerce.bigint().gte(0n).lte(18446744073709551615n))"
+        );
+        assert_eq!(
+            schema
+                .with_integer_mode(IntegerMode::String)
+                .with_integer_mode(IntegerMode::BigInt),
+            r"z.array(z.string().regex(/^\d+$/))"
+        );
+        assert_eq!(
+            ZodSchema::Integer {
+                min: i64::MIN.into(),
+                max: i64::MAX.into(),
+                mode: IntegerMode::String
+            },
+            r"z.string().regex(/^-?\d+$/)"
+        );
+
+        assert_eq!(ty.clone(), "Array<number>");
+        assert_eq!(ty.with_integer_mode(IntegerMode::BigInt), "Array<bigint>");
+    }
+
+    #[test]
+    fn render_enums() {
+        let schema = ZodSchema::Enum(vec![EnumMember::new("A", "A"), EnumMember::new("B", "B")]);
+        assert_eq!(schema, "z.enum([\"A\", \"B\"])");
+
+        let schema = ZodSchema::Enum(vec![EnumMember::new("A", 0), EnumMember::new("B", -1)]);
+        assert_eq!(schema, "z.union([z.literal(0), z.literal(-1)])");
+
+        let schema = ZodSchema::NativeEnum {
+            ns_name: "Ns",
+            name: "ColorEnum",
+            members: vec![EnumMember::new("Red", "Red")],
+        };
+        assert_eq!(schema, "z.nativeEnum(Ns.ColorEnum)");
+    }
+
+    #[test]
+    fn option_modes() {
+        let schema = ZodSchema::Boolean.nullable();
+        assert_eq!(schema.clone(), "z.boolean().nullable()");
+        assert_eq!(schema.clone().optional(), "z.boolean().nullish()");
+        assert_eq!(
+            schema.clone().with_option_mode(OptionMode::Optional),
+            "z.boolean().optional()"
+        );
+        assert_eq!(
+            ZodSchema::Boolean.with_option_mode(OptionMode::Optional),
+            "z.boolean()"
+        );
+
+        let field = TsField::new("a", TsType::Boolean.nullable());
+        assert_eq!(field.to_string(), "a: boolean | null");
+        assert_eq!(
+            field
+                .clone()
+                .with_option_mode(OptionMode::Optional)
+                .to_string(),
+            "a?: boolean | undefined"
+        );
+        assert_eq!(
+            field.with_option_mode(OptionMode::Nullish).to_string(),
+            "a?: boolean | null | undefined"
+        );
+    }
+
+    #[test]
+    fn render_refinements() {
+        let schema = ZodSchema::String(Vec::new())
+            .nullable()
+            .refine(Refinement::MinLength(3))
+            .refine(Refinement::Regex(String::from(r"^a/b\/c$")));
+
+        assert_eq!(schema, r"z.string().min(3).regex(/^a\/b\/c$/).nullable()");
+        assert_eq!(
+            ZodSchema::Array(Box::new(ZodSchema::Boolean)).refine(Refinement::NonEmpty),
+            "z.array(z.boolean()).min(1)"
+        );
+    }
+
+    #[test]
+    fn render_records() {
+        let schema = ZodSchema::Record(
+            Box::new(ZodSchema::String(vec![StringCheck::Regex(String::from(
+                r"^\d+$",
+            ))])),
+            Box::new(ZodSchema::Boolean),
+        );
+
+        let ty = TsType::Partial(Box::new(TsType::Record(
+            Box::new(TsType::Union(vec![
+                TsType::literal("A"),
+                TsType::literal("B"),
+            ])),
+            Box::new(TsType::Boolean),
+        )));
+
+        assert_eq!(schema, r"z.record(z.string().regex(/^\d+$/), z.boolean())");
+        assert_eq!(ty, "Partial<Record<\"A\" | \"B\", boolean>>");
+    }
+}
@@ -0,0 +1,118 @@
+//! Serde helpers for integers which exceed `Number.MAX_SAFE_INTEGER`.
+//!
+//! Javascript parses every JSON number into a float. Integers like `u64` are therefore sent as
+//! decimal strings when they are represented with [IntegerMode::BigInt] or
+//! [IntegerMode::String](crate::ast::IntegerMode::String):
+//!
+//! ```
+//! # use serde::{Serialize, Deserialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Account {
+//!     #[serde(with = "zod_core::bigint::as_string")]
+//!     balance: u128,
+//! }
+//!
+//! let json = serde_json::to_string(&Account { balance: u128::MAX }).unwrap();
+//! assert_eq!(json, r#"{"balance":"340282366920938463463374607431768211455"}"#);
+//! ```
+//!
+//! [IntegerMode::BigInt]: crate::ast::IntegerMode::BigInt
+
+/// Serialize an integer as a decimal string. Deserialization accepts strings and numbers.
+pub mod as_string {
+    use std::fmt::{self, Display};
+    use std::marker::PhantomData;
+    use std::str::FromStr;
+
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr + TryFrom<i128> + TryFrom<u128>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(IntegerVisitor(PhantomData))
+    }
+
+    struct IntegerVisitor<T>(PhantomData<T>);
+
+    impl<T> IntegerVisitor<T> {
+        fn out_of_range<E: de::Error>(value: impl Display) -> E {
+            E::custom(format_args!("integer {value} is out of range"))
+        }
+    }
+
+    impl<'de, T> Visitor<'de> for IntegerVisitor<T>
+    where
+        T: FromStr + TryFrom<i128> + TryFrom<u128>,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an integer or a string containing an integer")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+            value
+                .parse()
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+            self.visit_i128(value.into())
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+            self.visit_u128(value.into())
+        }
+
+        fn visit_i128<E: de::Error>(self, value: i128) -> Result<T, E> {
+            T::try_from(value).map_err(|_| Self::out_of_range(value))
+        }
+
+        fn visit_u128<E: de::Error>(self, value: u128) -> Result<T, E> {
+            T::try_from(value).map_err(|_| Self::out_of_range(value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Test {
+        #[serde(with = "super::as_string")]
+        value: i64,
+    }
+
+    #[test]
+    fn as_string() {
+        let value = Test { value: i64::MIN };
+
+        assert_eq!(
+            serde_json::to_value(&value).unwrap(),
+            json!({ "value": "-9223372036854775808" })
+        );
+        assert_eq!(
+            serde_json::from_value::<Test>(json!({ "value": "-9223372036854775808" })).unwrap(),
+            value
+        );
+        assert_eq!(
+            serde_json::from_value::<Test>(json!({ "value": 1 })).unwrap(),
+            Test { value: 1 }
+        );
+        assert!(serde_json::from_value::<Test>(json!({ "value": "a" })).is_err());
+        assert!(serde_json::from_value::<Test>(json!({ "value": u64::MAX })).is_err());
+    }
+}
@@ -1,23 +1,18 @@
+use crate::ast::{
+    IntegerMode, IpVersion, NumberCheck, Numeric, StringCheck, TsField, TsType, ZodField, ZodSchema,
+};
 use crate::TsTypeDef;
-use crate::ZodType;
+use crate::{ZodMapKey, ZodType};
 
 macro_rules! impl_primitive {
-    ($name:literal, $T:ty, $schema: literal) => {
+    ($T:ty => $ts:expr, $zod:expr) => {
         impl ZodType for $T {
-            /// ```ts
-            /// // TS-type
-            #[doc = $name]
-            /// ```
             fn type_def() -> TsTypeDef {
-                TsTypeDef::Type(String::from($name))
+                TsTypeDef::Type($ts)
             }
 
-            /// ```ts
-            /// // zod schema
-            #[doc = $schema]
-            /// ```
-            fn schema() -> String {
-                String::from($schema)
+            fn schema() -> ZodSchema {
+                $zod
             }
         }
     };
@@ -32,10 +27,17 @@ macro_rules! impl_shadow {
             }
 
             #[doc = concat!("shadows impl for [", $other, "](#impl-ZodType-for-", $other_link, ")")]
-            fn schema() -> String {
-                String::from(<$s>::schema())
+            fn schema() -> ZodSchema {
+                <$s>::schema()
+            }
+
+            fn input_type_def() -> TsTypeDef {
+                <$s>::input_type_def()
             }
 
+            fn input_schema() -> ZodSchema {
+                <$s>::input_schema()
+            }
         }
     };
 }
@@ -48,7 +50,7 @@ macro_rules! impl_tuples {
             /// [T1, T2, ... ]
             /// ```
             fn type_def() -> TsTypeDef {
-                TsTypeDef::Type(format!("[{}]", vec![$($i::type_def().to_string()),*].join(", ")))
+                TsTypeDef::Type(TsType::Tuple(vec![$($i::inline().into()),*]))
             }
 
 
@@ -56,8 +58,16 @@ macro_rules! impl_tuples {
             /// // zod schema
             /// z.tuple([T1, T2, ... ])
             /// ```
-            fn schema() -> String {
-                format!("z.tuple([{}])", vec![$($i::schema()),*].join(", "))
+            fn schema() -> ZodSchema {
+                ZodSchema::Tuple(vec![$($i::inline_schema()),*])
+            }
+
+            fn input_type_def() -> TsTypeDef {
+                TsTypeDef::Type(TsType::Tuple(vec![$($i::inline_input().into()),*]))
+            }
+
+            fn input_schema() -> ZodSchema {
+                ZodSchema::Tuple(vec![$($i::inline_input_schema()),*])
             }
         }
     };
@@ -83,69 +93,93 @@ macro_rules! impl_wrapper {
             /// // zod schema
             /// T
             /// ```
-            fn schema() -> String {
+            fn schema() -> ZodSchema {
                 T::schema()
             }
 
+            fn inline_schema() -> ZodSchema {
+                T::inline_schema()
+            }
+
+            fn inline() -> crate::InlinedType {
+                T::inline()
+            }
+
+            fn input_type_def() -> TsTypeDef {
+                T::input_type_def()
+            }
+
+            fn input_schema() -> ZodSchema {
+                T::input_schema()
+            }
+
+            fn inline_input_schema() -> ZodSchema {
+                T::inline_input_schema()
+            }
+
+            fn inline_input() -> crate::InlinedType {
+                T::inline_input()
+            }
         }
     };
 }
 
-impl_primitive!("string", String, "z.string()");
-impl_primitive!("string", &str, "z.string()");
+fn unsigned(max: impl Into<Numeric>) -> ZodSchema {
+    ZodSchema::Number(vec![
+        NumberCheck::Finite,
+        NumberCheck::Int,
+        NumberCheck::Nonnegative,
+        NumberCheck::Lte(max.into()),
+    ])
+}
 
-impl_primitive!(
-    "number",
-    u8,
-    "z.number().finite().int().nonnegative().lte(255)"
-);
-impl_primitive!(
-    "number",
-    u16,
-    "z.number().finite().int().nonnegative().lte(65535)"
-);
-impl_primitive!(
-    "number",
-    u32,
-    "z.number().finite().int().nonnegative().lte(4294967295)"
-);
-impl_primitive!(
-    "number",
-    u64,
-    "z.number().finite().int().nonnegative().lte(18446744073709551615)"
-);
-impl_primitive!(
-    "number",
-    u128,
-    "z.number().finite().int().nonnegative().lte(340282366920938463463374607431768211455)"
-);
-impl_primitive!("number", usize, "z.number().finite().int().nonnegative()");
+fn signed(min: impl Into<Numeric>, max: impl Into<Numeric>) -> ZodSchema {
+    ZodSchema::Number(vec![
+        NumberCheck::Finite,
+        NumberCheck::Int,
+        NumberCheck::Lte(max.into()),
+        NumberCheck::Gte(min.into()),
+    ])
+}
 
-impl_primitive!("number", i8, "z.number().finite().int().lte(127).gte(-128)");
-impl_primitive!(
-    "number",
-    i16,
-    "z.number().finite().int().lte(32767).gte(-32768)"
-);
+/// an integer which exceeds `Number.MAX_SAFE_INTEGER`, see [IntegerMode]
+fn wide_integer(min: impl Into<Numeric>, max: impl Into<Numeric>) -> ZodSchema {
+    ZodSchema::Integer {
+        min: min.into(),
+        max: max.into(),
+        mode: IntegerMode::Number,
+    }
+}
+
+impl_primitive!(String => TsType::String, ZodSchema::String(Vec::new()));
+impl_primitive!(&str => TsType::String, ZodSchema::String(Vec::new()));
+
+impl_primitive!(u8 => TsType::Number, unsigned(u8::MAX));
+impl_primitive!(u16 => TsType::Number, unsigned(u16::MAX));
+impl_primitive!(u32 => TsType::Number, unsigned(u32::MAX));
+impl_primitive!(u64 => TsType::Integer(IntegerMode::Number), wide_integer(0u8, u64::MAX));
+impl_primitive!(u128 => TsType::Integer(IntegerMode::Number), wide_integer(0u8, u128::MAX));
 impl_primitive!(
-    "number",
-    i32,
-    "z.number().finite().int().lte(2147483647).gte(-2147483648)"
+    usize => TsType::Number,
+    ZodSchema::Number(vec![NumberCheck::Finite, NumberCheck::Int, NumberCheck::Nonnegative])
 );
+
+impl_primitive!(i8 => TsType::Number, signed(i8::MIN, i8::MAX));
+impl_primitive!(i16 => TsType::Number, signed(i16::MIN, i16::MAX));
+impl_primitive!(i32 => TsType::Number, signed(i32::MIN, i32::MAX));
+impl_primitive!(i64 => TsType::Integer(IntegerMode::Number), wide_integer(i64::MIN, i64::MAX));
+impl_primitive!(i128 => TsType::Integer(IntegerMode::Number), wide_integer(i128::MIN, i128::MAX));
 impl_primitive!(
-    "number",
-    i64,
-    "z.number().finite().int().lte(9223372036854775807).gte(-9223372036854775808)"
+    isize => TsType::Number,
+    ZodSchema::Number(vec![NumberCheck::Finite, NumberCheck::Int])
 );
-impl_primitive!("number", i128, "z.number().finite().int().lte(170141183460469231731687303715884105727).gte(-170141183460469231731687303715884105728)");
-impl_primitive!("number", isize, "z.number().finite().int()");
 
-impl_primitive!("number", f32, "z.number()");
-impl_primitive!("number", f64, "z.number()");
+impl_primitive!(f32 => TsType::Number, ZodSchema::Number(Vec::new()));
+impl_primitive!(f64 => TsType::Number, ZodSchema::Number(Vec::new()));
 
-impl_primitive!("boolean", bool, "z.bool()");
-impl_primitive!("string", char, "z.string().length(1)");
-impl_primitive!("null", (), "z.null()");
+impl_primitive!(bool => TsType::Boolean, ZodSchema::Boolean);
+impl_primitive!(char => TsType::String, ZodSchema::String(vec![StringCheck::Length(1)]));
+impl_primitive!(() => TsType::Null, ZodSchema::Null);
 
 impl_tuples!(
     T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17, T18, T19, T20, T21,
@@ -163,7 +197,7 @@ impl_wrapper!(impl<T: ZodType> ZodType for std::sync::Weak<T>);
 impl_wrapper!(impl<T: ZodType> ZodType for std::marker::PhantomData<T>);
 
 impl_shadow!("HashSet", "HashSet&lt;T,+RandomState&gt;", std::collections::HashSet<T>; impl<T: ZodType> ZodType for std::collections::BTreeSet<T>);
-impl_shadow!("HashMap", "HashMap&lt;K,+V,+RandomState&gt;", std::collections::HashMap<K, V>; impl<K: ZodType, V: ZodType> ZodType for std::collections::BTreeMap<K, V>);
+impl_shadow!("HashMap", "HashMap&lt;K,+V,+RandomState&gt;", std::collections::HashMap<K, V>; impl<K: ZodMapKey, V: ZodType> ZodType for std::collections::BTreeMap<K, V>);
 
 impl_shadow!("Vec", "Vec&lt;T&gt;", Vec<T>; impl<T: ZodType, const N: usize> ZodType for [T; N]);
 
@@ -173,70 +207,173 @@ impl<T: ZodType> ZodType for Vec<T> {
     /// Array<T>
     /// ```
     fn type_def() -> TsTypeDef {
-        TsTypeDef::Type(format!("Array<{}>", T::type_def()))
+        TsTypeDef::Type(TsType::Array(Box::new(T::inline().into())))
     }
 
     /// ```ts
     /// // zod schema
     /// z.array(z.lazy(() => T))
     /// ```
-    fn schema() -> String {
-        format!("z.array({})", T::inline_schema())
+    fn schema() -> ZodSchema {
+        ZodSchema::Array(Box::new(T::inline_schema()))
+    }
+
+    fn input_type_def() -> TsTypeDef {
+        TsTypeDef::Type(TsType::Array(Box::new(T::inline_input().into())))
+    }
+
+    fn input_schema() -> ZodSchema {
+        ZodSchema::Array(Box::new(T::inline_input_schema()))
     }
 }
 
 impl<T: ZodType> ZodType for std::collections::HashSet<T> {
     /// ```ts
     /// // TS-type
-    /// Set<T>
+    /// Array<T>
     /// ```
     fn type_def() -> TsTypeDef {
-        TsTypeDef::Type(format!("Set<{}>", T::type_def()))
+        TsTypeDef::Type(TsType::Array(Box::new(T::inline().into())))
     }
 
     /// ```ts
     /// // zod schema
-    /// z.set(z.lazy(() => T))
+    /// z.array(z.lazy(() => T))
     /// ```
-    fn schema() -> String {
-        format!("z.set({})", T::inline_schema())
+    fn schema() -> ZodSchema {
+        ZodSchema::Array(Box::new(T::inline_schema()))
+    }
+
+    fn input_type_def() -> TsTypeDef {
+        TsTypeDef::Type(TsType::Array(Box::new(T::inline_input().into())))
+    }
+
+    fn input_schema() -> ZodSchema {
+        ZodSchema::Array(Box::new(T::inline_input_schema()))
     }
 }
 
-impl<K: ZodType, V: ZodType> ZodType for std::collections::HashMap<K, V> {
+impl<K: ZodMapKey, V: ZodType> ZodType for std::collections::HashMap<K, V> {
     /// ```ts
     /// // TS-type
-    /// Map<K, V>
+    /// Record<K, V>
     /// ```
     fn type_def() -> TsTypeDef {
-        TsTypeDef::Type(format!("Map<{}, {}>", K::type_def(), V::type_def()))
+        record::<K>(V::inline().into())
     }
 
     /// ```ts
     /// // zod schema
-    /// z.map(z.lazy(() => K), z.lazy(() => V))
+    /// z.record(z.lazy(() => K), z.lazy(() => V))
     /// ```
-    fn schema() -> String {
-        format!("z.map({}, {})", K::inline_schema(), V::inline_schema())
+    fn schema() -> ZodSchema {
+        ZodSchema::Record(Box::new(K::key_schema()), Box::new(V::inline_schema()))
+    }
+
+    fn input_type_def() -> TsTypeDef {
+        record::<K>(V::inline_input().into())
+    }
+
+    fn input_schema() -> ZodSchema {
+        ZodSchema::Record(
+            Box::new(K::key_schema()),
+            Box::new(V::inline_input_schema()),
+        )
+    }
+}
+
+fn record<K: ZodMapKey>(value: TsType) -> TsTypeDef {
+    let record = TsType::Record(Box::new(K::key_type()), Box::new(value));
+
+    if K::finite() {
+        TsTypeDef::Type(TsType::Partial(Box::new(record)))
+    } else {
+        TsTypeDef::Type(record)
     }
 }
 
+macro_rules! impl_map_key {
+    ($($T:ty),*) => {
+        $(impl ZodMapKey for $T {})*
+    };
+}
+
+macro_rules! impl_integer_map_key {
+    ($pattern:literal: $($T:ty),*) => {
+        $(impl ZodMapKey for $T {
+            /// integer keys are stringified by serde
+            fn key_schema() -> ZodSchema {
+                ZodSchema::String(vec![StringCheck::Regex(String::from($pattern))])
+            }
+
+            fn key_type() -> TsType {
+                TsType::String
+            }
+        })*
+    };
+}
+
+impl_map_key!(
+    String,
+    &str,
+    char,
+    std::net::Ipv4Addr,
+    std::net::Ipv6Addr,
+    std::net::IpAddr
+);
+
+impl_integer_map_key!(r"^\d+$": u8, u16, u32, u64, u128, usize);
+impl_integer_map_key!(r"^-?\d+$": i8, i16, i32, i64, i128, isize);
+
+#[cfg(feature = "smol_str")]
+impl_map_key!(smol_str::SmolStr);
+
+macro_rules! impl_wrapper_map_key {
+    ($($t:tt)*) => {
+        $($t)* {
+            fn key_schema() -> ZodSchema {
+                T::key_schema()
+            }
+
+            fn key_type() -> TsType {
+                T::key_type()
+            }
+
+            fn finite() -> bool {
+                T::finite()
+            }
+        }
+    };
+}
+
+impl_wrapper_map_key!(impl<T: ZodMapKey> ZodMapKey for Box<T>);
+impl_wrapper_map_key!(impl<T: ZodMapKey> ZodMapKey for std::sync::Arc<T>);
+impl_wrapper_map_key!(impl<T: ZodMapKey> ZodMapKey for std::rc::Rc<T>);
+impl_wrapper_map_key!(impl<T: ZodMapKey + ToOwned> ZodMapKey for std::borrow::Cow<'static, T>);
+
 impl<T: ZodType> ZodType for Option<T> {
     /// ```ts
     /// // TS-type
-    /// T | undefined
+    /// T | null
     /// ```
     fn type_def() -> TsTypeDef {
-        // Todo
-        TsTypeDef::Type(format!("({} | undefined)", T::type_def()))
+        TsTypeDef::Type(TsType::from(T::inline()).nullable())
     }
 
     /// ```ts
     /// // zod schema
-    /// z.lazy(() => T).optional()
+    /// z.lazy(() => T).nullable()
     /// ```
-    fn schema() -> String {
-        format!("{}.optional()", T::inline_schema())
+    fn schema() -> ZodSchema {
+        T::inline_schema().nullable()
+    }
+
+    fn input_type_def() -> TsTypeDef {
+        TsTypeDef::Type(TsType::from(T::inline_input()).nullable())
+    }
+
+    fn input_schema() -> ZodSchema {
+        T::inline_input_schema().nullable()
     }
 }
 
@@ -246,11 +383,10 @@ impl<T: ZodType, E: ZodType> ZodType for Result<T, E> {
     /// { Ok: T } | { Err: E }
     /// ```
     fn type_def() -> TsTypeDef {
-        TsTypeDef::Type(format!(
-            "{{ Ok: {} }} | {{ Err: {} }}",
-            T::type_def(),
-            E::type_def()
-        ))
+        TsTypeDef::Type(TsType::Union(vec![
+            TsType::Object(vec![TsField::new("Ok", T::inline().into())]),
+            TsType::Object(vec![TsField::new("Err", E::inline().into())]),
+        ]))
     }
 
     /// ```ts
@@ -260,41 +396,110 @@ impl<T: ZodType, E: ZodType> ZodType for Result<T, E> {
     ///   z.object({ Err: E })
     /// ])
     /// ```
-    fn schema() -> String {
-        format!(
-            "z.union([z.object({{ Ok: {} }}), z.object({{ Err: {} }})])",
-            T::inline_schema(),
-            E::inline_schema()
-        )
+    fn schema() -> ZodSchema {
+        ZodSchema::Union(vec![
+            ZodSchema::object(vec![ZodField::new("Ok", T::inline_schema())]),
+            ZodSchema::object(vec![ZodField::new("Err", E::inline_schema())]),
+        ])
+    }
+
+    fn input_type_def() -> TsTypeDef {
+        TsTypeDef::Type(TsType::Union(vec![
+            TsType::Object(vec![TsField::new("Ok", T::inline_input().into())]),
+            TsType::Object(vec![TsField::new("Err", E::inline_input().into())]),
+        ]))
+    }
+
+    fn input_schema() -> ZodSchema {
+        ZodSchema::Union(vec![
+            ZodSchema::object(vec![ZodField::new("Ok", T::inline_input_schema())]),
+            ZodSchema::object(vec![ZodField::new("Err", E::inline_input_schema())]),
+        ])
     }
 }
 
 impl_primitive!(
-    "string",
-    std::net::Ipv4Addr,
-    "z.string().ip({ version: \"v4\" })"
+    std::net::Ipv4Addr => TsType::String,
+    ZodSchema::String(vec![StringCheck::Ip(Some(IpVersion::V4))])
 );
 
 impl_primitive!(
-    "string",
-    std::net::Ipv6Addr,
-    "z.string().ip({ version: \"v6\" })"
+    std::net::Ipv6Addr => TsType::String,
+    ZodSchema::String(vec![StringCheck::Ip(Some(IpVersion::V6))])
 );
 
-impl_primitive!("string", std::net::IpAddr, "z.string().ip()");
+impl_primitive!(std::net::IpAddr => TsType::String, ZodSchema::String(vec![StringCheck::Ip(None)]));
 
 #[cfg(feature = "smol_str")]
-impl_primitive!("string", smol_str::SmolStr, "z.string()");
+impl_primitive!(smol_str::SmolStr => TsType::String, ZodSchema::String(Vec::new()));
 
 #[cfg(feature = "ordered-float")]
-impl_primitive!("number", ordered_float::NotNan<f32>, "z.number()");
+impl_primitive!(ordered_float::NotNan<f32> => TsType::Number, ZodSchema::Number(Vec::new()));
 
 #[cfg(feature = "ordered-float")]
-impl_primitive!("number", ordered_float::NotNan<f64>, "z.number()");
+impl_primitive!(ordered_float::NotNan<f64> => TsType::Number, ZodSchema::Number(Vec::new()));
 
 #[cfg(test)]
 mod test {
     use super::ZodType;
+    use std::collections::{BTreeSet, HashMap};
+
+    #[test]
+    fn primitives() {
+        assert_eq!(String::schema(), "z.string()");
+        assert_eq!(bool::schema(), "z.boolean()");
+        assert_eq!(bool::type_def(), "boolean");
+        assert_eq!(char::schema(), "z.string().length(1)");
+        assert_eq!(<()>::schema(), "z.null()");
+        assert_eq!(
+            u8::schema(),
+            "z.number().finite().int().nonnegative().lte(255)"
+        );
+        assert_eq!(
+            i32::schema(),
+            "z.number().finite().int().lte(2147483647).gte(-2147483648)"
+        );
+        assert_eq!(usize::schema(), "z.number().finite().int().nonnegative()");
+        assert_eq!(
+            std::net::Ipv4Addr::schema(),
+            "z.string().ip({ version: \"v4\" })"
+        );
+    }
+
+    #[test]
+    fn maps_are_records() {
+        let map: HashMap<u8, bool> = HashMap::from([(1, true)]);
+        assert_eq!(
+            serde_json::to_value(map).unwrap(),
+            serde_json::json!({"1": true})
+        );
+
+        assert_eq!(
+            HashMap::<String, bool>::schema(),
+            "z.record(z.string(), z.boolean())"
+        );
+        assert_eq!(
+            HashMap::<String, bool>::type_def(),
+            "Record<string, boolean>"
+        );
+        assert_eq!(
+            HashMap::<i32, bool>::schema(),
+            r"z.record(z.string().regex(/^-?\d+$/), z.boolean())"
+        );
+        assert_eq!(HashMap::<u64, bool>::type_def(), "Record<string, boolean>");
+    }
+
+    #[test]
+    fn sets_are_arrays() {
+        let set = BTreeSet::from([1, 2]);
+        assert_eq!(
+            serde_json::to_value(set).unwrap(),
+            serde_json::json!([1, 2])
+        );
+
+        assert_eq!(BTreeSet::<bool>::schema(), "z.array(z.boolean())");
+        assert_eq!(BTreeSet::<bool>::type_def(), "Array<boolean>");
+    }
 
     #[test]
     fn result_ok() {
@@ -7,25 +7,27 @@ struct T;
 struct U;
 
 impl crate::ZodType for T {
-    fn schema() -> String {
-        String::from("T")
+    fn schema() -> crate::ast::ZodSchema {
+        crate::ast::ZodSchema::Raw(String::from("T"))
     }
 
     fn type_def() -> crate::TsTypeDef {
-        crate::TsTypeDef::Type(String::from("T"))
+        crate::TsTypeDef::Type(crate::ast::TsType::Raw(String::from("T")))
     }
 }
 
 impl crate::ZodType for U {
-    fn schema() -> String {
-        String::from("U")
+    fn schema() -> crate::ast::ZodSchema {
+        crate::ast::ZodSchema::Raw(String::from("U"))
     }
 
     fn type_def() -> crate::TsTypeDef {
-        crate::TsTypeDef::Type(String::from("U"))
+        crate::TsTypeDef::Type(crate::ast::TsType::Raw(String::from("U")))
     }
 }
 
+impl crate::ZodMapKey for T {}
+
 type E = U;
 
 pub fn generate() -> String {
@@ -106,7 +108,7 @@ impl Line {
                 None => format!("[{name}]"),
             },
 
-            schema: T::schema(),
+            schema: T::schema().to_string(),
         }
     }
 }
@@ -5,19 +5,31 @@
 #[cfg(feature = "rpc")]
 pub mod rpc;
 
+pub mod ast;
+#[cfg(feature = "serde")]
+pub mod bigint;
 mod build_ins;
+pub mod validation;
+
+#[cfg(feature = "validate")]
+pub mod validate;
+
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
 
 #[cfg(debug_assertions)]
 pub mod docs;
 
-use std::collections::BTreeMap;
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
 
-pub use build_ins::*;
+use ast::{TsType, ZodSchema};
 
 pub trait ZodType {
-    fn schema() -> String;
+    fn schema() -> ZodSchema;
 
-    fn inline_schema() -> String {
+    fn inline_schema() -> ZodSchema {
         Self::schema()
     }
 
@@ -28,15 +40,66 @@ pub trait ZodType {
     }
 
     fn inline() -> InlinedType {
-        InlinedType::Literal(Self::type_def().to_string())
+        InlinedType::Literal(Self::type_def().into())
+    }
+
+    /// The schema of values which are deserialized by rust, eg. rpc arguments. It differs from
+    /// [ZodType::schema] if serde treats both directions differently, eg. for
+    /// `#[serde(skip_serializing)]` or `#[serde(default)]`.
+    fn input_schema() -> ZodSchema {
+        Self::schema()
+    }
+
+    fn inline_input_schema() -> ZodSchema {
+        Self::input_schema()
+    }
+
+    fn input_type_def() -> TsTypeDef {
+        Self::type_def()
+    }
+
+    fn inline_input() -> InlinedType {
+        InlinedType::Literal(Self::input_type_def().into())
+    }
+}
+
+/// Types which can be used as the key of a map.
+///
+/// JSON objects only have string keys, so maps are represented as `z.record(K, V)` and every key
+/// type has to serialize to a string. Numeric keys are stringified by serde and are validated as
+/// strings of digits.
+///
+/// Derived newtypes over a generic parameter are keys whenever the parameter is one, newtypes over
+/// a concrete key type opt in with `#[zod(map_key)]`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as the key of a JSON object",
+    label = "maps are serialized as JSON objects which only support string keys",
+    note = "use a string-like or integer key type, a unit-only enum or a newtype wrapping one of them with `#[zod(map_key)]`"
+)]
+pub trait ZodMapKey: ZodType {
+    /// the schema of the key as it appears in JSON
+    fn key_schema() -> ZodSchema {
+        Self::inline_schema()
+    }
+
+    fn key_type() -> TsType {
+        Self::inline().into()
+    }
+
+    /// whether the key only has a finite set of values, e.g. a unit-only enum. Records of such
+    /// keys don't contain every key and are typed as `Partial<Record<K, V>>`
+    fn finite() -> bool {
+        false
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
 pub enum InlinedType {
-    Literal(String),
+    Literal(TsType),
     Ref {
         ns_name: &'static str,
         name: &'static str,
+        args: Vec<TsType>,
     },
 }
 
@@ -44,9 +107,24 @@ impl std::fmt::Display for InlinedType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Literal(inner) => write!(f, "{}", inner),
-            Self::Ref { ns_name, name } => {
-                write!(f, "{}.{}", ns_name, name)
-            }
+            Self::Ref { .. } => write!(f, "{}", TsType::from(self.clone())),
+        }
+    }
+}
+
+impl From<InlinedType> for TsType {
+    fn from(value: InlinedType) -> Self {
+        match value {
+            InlinedType::Literal(inner) => inner,
+            InlinedType::Ref {
+                ns_name,
+                name,
+                args,
+            } => TsType::Ref {
+                ns_name,
+                name,
+                args,
+            },
         }
     }
 }
@@ -60,8 +138,8 @@ pub trait Namespace {
 
     #[cfg(feature = "inventory")]
     fn members() -> Vec<&'static NamespaceMemberDefinition> {
-        let members = inventory::iter::<NamespaceMemberDefinition>()
-            .filter(|namespace| namespace.ns_name == Self::NAME);
+        let members =
+            NamespaceMemberDefinition::iter().filter(|namespace| namespace.ns_name == Self::NAME);
 
         members.collect()
     }
@@ -72,9 +150,16 @@ type RuntimeValue<T> = &'static (dyn Fn() -> T + Sync);
 pub struct NamespaceMemberDefinition {
     ns_name: &'static str,
     name: &'static str,
-    schema: RuntimeValue<String>,
+    schema: RuntimeValue<ZodSchema>,
     type_def: RuntimeValue<TsTypeDef>,
     docs: RuntimeValue<Option<&'static str>>,
+    generics: &'static [&'static str],
+    /// whether the member is emitted, see [input_differs]
+    emitted: RuntimeValue<bool>,
+}
+
+fn always() -> bool {
+    true
 }
 
 impl NamespaceMemberDefinition {
@@ -86,9 +171,36 @@ impl NamespaceMemberDefinition {
             schema: &<T as ZodType>::schema,
             type_def: &<T as ZodType>::type_def,
             docs: &<T as ZodType>::docs,
+            generics: &[],
+            emitted: &always,
         }
     }
 
+    /// Register the input flavour of `T`, see [ZodType::input_schema]. It is only emitted if it
+    /// differs from the output flavour.
+    #[doc(hidden)]
+    pub const fn new_input_for<T: ZodType + 'static>(
+        ns_name: &'static str,
+        name: &'static str,
+    ) -> Self {
+        Self {
+            ns_name,
+            name,
+            schema: &<T as ZodType>::input_schema,
+            type_def: &<T as ZodType>::input_type_def,
+            docs: &<T as ZodType>::docs,
+            generics: &[],
+            emitted: &input_differs::<T>,
+        }
+    }
+
+    /// Register a generic type. `T` must be instantiated with placeholders which render as the
+    /// names of its generic parameters.
+    #[doc(hidden)]
+    pub const fn with_generics(self, generics: &'static [&'static str]) -> Self {
+        Self { generics, ..self }
+    }
+
     pub fn namespace(&self) -> &'static str {
         self.ns_name
     }
@@ -96,7 +208,7 @@ impl NamespaceMemberDefinition {
         self.name
     }
 
-    pub fn schema(&self) -> String {
+    pub fn schema(&self) -> ZodSchema {
         (self.schema)()
     }
 
@@ -104,23 +216,97 @@ impl NamespaceMemberDefinition {
         (self.type_def)()
     }
 
+    /// names of the generic parameters of the member
+    pub fn generics(&self) -> &'static [&'static str] {
+        self.generics
+    }
+
+    /// the registered members, input flavours which don't differ from the output are skipped
+    pub fn iter() -> impl Iterator<Item = &'static NamespaceMemberDefinition> {
+        inventory::iter::<NamespaceMemberDefinition>().filter(|def| (def.emitted)())
+    }
+
     pub fn collect() -> BTreeMap<&'static str, Vec<&'static NamespaceMemberDefinition>> {
         let mut out = BTreeMap::<&'static str, Vec<&'static NamespaceMemberDefinition>>::default();
-        for def in inventory::iter::<NamespaceMemberDefinition>() {
+        for def in Self::iter() {
             out.entry(def.namespace()).or_default().push(def);
         }
         out
     }
 }
 
+#[derive(Default)]
+struct InputComparisons {
+    known: HashMap<TypeId, bool>,
+    in_progress: Vec<TypeId>,
+    /// the outermost in progress comparison which was assumed to be equal
+    lowest: Option<usize>,
+}
+
+thread_local! {
+    static INPUT_COMPARISONS: RefCell<InputComparisons> = RefCell::default();
+}
+
+/// Whether the input flavour of `T` differs from its output flavour. Identical input flavours are
+/// not emitted, references to them use the name of the output flavour instead.
+///
+/// Recursive types refer to themselves while they are compared. These references are assumed to
+/// be equal, a result which depends on such an assumption is only cached once the comparison it
+/// was made for has finished.
+#[doc(hidden)]
+pub fn input_differs<T: ZodType + 'static>() -> bool {
+    let id = TypeId::of::<T>();
+
+    let started = INPUT_COMPARISONS.with(|state| {
+        let mut state = state.borrow_mut();
+
+        if let Some(differs) = state.known.get(&id) {
+            return Err(*differs);
+        }
+
+        if let Some(index) = state.in_progress.iter().position(|other| *other == id) {
+            state.lowest = Some(state.lowest.map_or(index, |lowest| lowest.min(index)));
+            return Err(false);
+        }
+
+        state.in_progress.push(id);
+        Ok((state.in_progress.len() - 1, state.lowest.take()))
+    });
+
+    let (index, outer_lowest) = match started {
+        Ok(started) => started,
+        Err(differs) => return differs,
+    };
+
+    let differs = T::input_schema() != T::schema() || T::input_type_def() != T::type_def();
+
+    INPUT_COMPARISONS.with(|state| {
+        let mut state = state.borrow_mut();
+        state.in_progress.pop();
+
+        // assuming equality only hides differences, a difference is therefore always final
+        let assumed = state.lowest.filter(|lowest| *lowest < index);
+        if differs || assumed.is_none() {
+            state.known.insert(id, differs);
+        }
+
+        state.lowest = match (outer_lowest, assumed) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+    });
+
+    differs
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum TsTypeDef {
-    Interface(String),
-    Type(String),
+    Interface(TsType),
+    Type(TsType),
 }
 
 impl std::ops::Deref for TsTypeDef {
-    type Target = String;
+    type Target = TsType;
 
     fn deref(&self) -> &Self::Target {
         match self {
@@ -130,6 +316,15 @@ impl std::ops::Deref for TsTypeDef {
     }
 }
 
+impl From<TsTypeDef> for TsType {
+    fn from(value: TsTypeDef) -> Self {
+        match value {
+            TsTypeDef::Interface(inner) => inner,
+            TsTypeDef::Type(inner) => inner,
+        }
+    }
+}
+
 impl std::fmt::Display for TsTypeDef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -141,15 +336,15 @@ impl std::fmt::Display for TsTypeDef {
 
 impl std::cmp::PartialEq<&str> for TsTypeDef {
     fn eq(&self, other: &&str) -> bool {
-        let s: &str = self;
-        (&s).eq(other)
+        let ty: &TsType = self;
+        ty.eq(other)
     }
 }
 
 impl std::cmp::PartialEq<str> for TsTypeDef {
     fn eq(&self, other: &str) -> bool {
-        let s: &str = self;
-        s.eq(other)
+        let ty: &TsType = self;
+        ty.eq(other)
     }
 }
 
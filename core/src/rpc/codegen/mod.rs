@@ -1,3 +1,6 @@
+use crate::ast::{jsdoc, TsType, ZodSchema};
+use crate::rpc::server::CodegenOptions;
+
 pub trait ClientCodegen {
     fn get() -> String;
 }
@@ -16,16 +19,16 @@ pub trait RpcNamespace: crate::Namespace {
 
 pub struct RpcArgument {
     name: &'static str,
-    type_def: String,
-    schema: String,
+    type_def: TsType,
+    schema: ZodSchema,
 }
 
 impl RpcArgument {
     pub fn new<T: crate::ZodType>(name: &'static str) -> Self {
         Self {
             name,
-            type_def: T::inline().to_string(),
-            schema: T::inline_schema(),
+            type_def: T::inline_input().into(),
+            schema: T::inline_input_schema(),
         }
     }
 }
@@ -35,13 +38,17 @@ pub enum RpcMember {
         ns_name: &'static str,
         name: &'static str,
         args: RuntimeValue<Vec<RpcArgument>>,
-        res: RuntimeValue<String>,
+        res: RuntimeValue<TsType>,
+        /// the JSDoc of the generated function, including `@deprecated`
+        docs: Option<&'static str>,
     },
     Stream {
         ns_name: &'static str,
         name: &'static str,
         args: RuntimeValue<Vec<RpcArgument>>,
-        res: RuntimeValue<String>,
+        res: RuntimeValue<TsType>,
+        /// the JSDoc of the generated function, including `@deprecated`
+        docs: Option<&'static str>,
     },
 }
 
@@ -59,20 +66,28 @@ fn create_phantom_arg_names(args: &[RpcArgument]) -> String {
 
 impl RpcMember {
     pub fn decl(&self) -> String {
+        self.decl_with_options(&Default::default())
+    }
+
+    pub fn decl_with_options(&self, options: &CodegenOptions) -> String {
         match self {
             RpcMember::Method {
                 name,
                 args,
                 res,
                 ns_name,
-                ..
+                docs,
             } => {
+                let docs = docs.map(jsdoc).unwrap_or_default();
                 let args = (args)();
-                let res = (res)();
+                let res = options.apply_to_type((res)());
 
                 let arg_fields = args
                     .iter()
-                    .map(|arg| format!("{}: {}", arg.name, arg.type_def))
+                    .map(|arg| {
+                        let ty = options.apply_to_type(arg.type_def.clone());
+                        format!("{}: {}", arg.name, ty)
+                    })
                     .collect::<Vec<_>>()
                     .join(",");
 
@@ -80,12 +95,13 @@ impl RpcMember {
 
                 let arg_zod = args
                     .iter()
-                    .map(|arg| arg.schema.to_owned())
+                    .map(|arg| options.apply_to_schema(arg.schema.clone()).to_string())
                     .collect::<Vec<_>>()
                     .join(",");
 
                 format!(
                     "
+                    {docs}
                     // @ts-ignore
                     export async function {name}({arg_fields}): Promise<{res}> {{
                     {phantom_arg_names}
@@ -100,14 +116,18 @@ impl RpcMember {
                 args,
                 res,
                 ns_name,
-                ..
+                docs,
             } => {
+                let docs = docs.map(jsdoc).unwrap_or_default();
                 let args = (args)();
-                let res = (res)();
+                let res = options.apply_to_type((res)());
 
                 let arg_fields = args
                     .iter()
-                    .map(|arg| format!("{}: {}", arg.name, arg.type_def))
+                    .map(|arg| {
+                        let ty = options.apply_to_type(arg.type_def.clone());
+                        format!("{}: {}", arg.name, ty)
+                    })
                     .collect::<Vec<_>>()
                     .join(",");
 
@@ -115,12 +135,13 @@ impl RpcMember {
 
                 let arg_zod = args
                     .iter()
-                    .map(|arg| arg.schema.to_owned())
+                    .map(|arg| options.apply_to_schema(arg.schema.clone()).to_string())
                     .collect::<Vec<_>>()
                     .join(",");
 
                 format!(
                     "
+                    {docs}
                     // @ts-ignore
                     export function {name}({arg_fields}): Store<{res}> {{
                     {phantom_arg_names}
@@ -4,6 +4,11 @@ pub enum Error {
     #[serde(serialize_with = "ser_display")]
     #[serde(rename = "JsonError")]
     Json(#[from] serde_json::Error),
+
+    /// the arguments of a call violate the refinements of their types
+    #[error("ValidationError: {0}")]
+    #[serde(rename = "ValidationError")]
+    Validation(#[from] crate::validation::ValidationErrors),
 }
 
 fn ser_display<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
@@ -1,6 +1,7 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::Write;
 
+use crate::ast::{jsdoc, schema_param, string_literal, EnumMember, IntegerMode, TsType, ZodSchema};
 use crate::NamespaceMemberDefinition;
 
 use crate::{
@@ -48,6 +49,208 @@ pub struct CodegenOptions {
 
     prefix_interface: String,
     suffix_interface: String,
+
+    integer_mode: IntegerMode,
+}
+
+impl CodegenOptions {
+    /// represent integers which exceed `Number.MAX_SAFE_INTEGER` with `mode` unless a field
+    /// overrides it, see [IntegerMode].
+    ///
+    /// Serde keeps sending these integers as JSON numbers, `z.coerce.bigint()` accepts them and the
+    /// client sends bigints back as JSON numbers. Results above `Number.MAX_SAFE_INTEGER` are only
+    /// exact for fields serialized with [as_string](crate::bigint::as_string).
+    ///
+    /// # Panics
+    ///
+    /// For [IntegerMode::String], serde can't deserialize a plain integer from the strings the
+    /// client would send. Use `#[zod(as_string)]` on the field instead.
+    pub fn integer_mode(self, mode: IntegerMode) -> Self {
+        assert!(
+            mode != IntegerMode::String,
+            "zod: the global integer mode can't be `String`, use `#[zod(as_string)]` on the field"
+        );
+
+        Self {
+            integer_mode: mode,
+            ..self
+        }
+    }
+
+    pub(crate) fn apply_to_schema(&self, schema: ZodSchema) -> ZodSchema {
+        schema.with_integer_mode(self.integer_mode)
+    }
+
+    pub(crate) fn apply_to_type(&self, ty: TsType) -> TsType {
+        ty.with_integer_mode(self.integer_mode)
+    }
+}
+
+/// The members of an intersection, nested intersections included
+fn intersected(ty: &TsType) -> Vec<&TsType> {
+    match ty {
+        TsType::Intersection(items) => items.iter().flat_map(intersected).collect(),
+        other => vec![other],
+    }
+}
+
+/// Members with the same name overwrite each other, eg. a type named `FooInput` and the input
+/// flavour of `Foo`. Besides its schema and type a member may declare `{Name}Enum` and
+/// `parse{Name}`, see [enum_declaration] and [brand_helper].
+fn check_unique_names(
+    ns_name: &str,
+    defs: &[&NamespaceMemberDefinition],
+    options: &CodegenOptions,
+) {
+    // typescript keeps values and types apart, a schema may share the name of its type
+    let mut values = HashSet::new();
+    let mut types = HashSet::new();
+
+    for def in defs {
+        let type_name = format!(
+            "{}{}{}",
+            options.prefix_type,
+            def.name(),
+            options.suffix_type
+        );
+        let mut def_values = vec![format!(
+            "{}{}{}",
+            options.prefix_schema,
+            def.name(),
+            options.suffix_schema
+        )];
+        let mut def_types = vec![type_name.clone()];
+
+        match def.schema() {
+            ZodSchema::Enum(_) => def_values.push(format!("{}Enum", def.name())),
+            ZodSchema::NativeEnum { name, .. } => {
+                def_values.push(name.to_string());
+                def_types.push(name.to_string());
+            }
+            ZodSchema::Branded(_, _) if def.generics().is_empty() => {
+                def_values.push(format!("parse{type_name}"))
+            }
+            _ => {}
+        }
+
+        for (names, declared) in [(&mut values, def_values), (&mut types, def_types)] {
+            for name in declared {
+                if !names.insert(name.clone()) {
+                    panic!(
+                        "zod: `{}` is declared twice in namespace `{}`, rename one of the types",
+                        name, ns_name
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Interfaces can only extend object types. A reference is one if it is declared as an interface
+/// which itself only extends object types.
+fn is_object_type(ty: &TsType, visited: &mut Vec<(&'static str, &'static str)>) -> bool {
+    match ty {
+        TsType::Object(_) => true,
+        TsType::Ref { ns_name, name, .. } => {
+            // flattening a struct into itself never ends
+            if visited.contains(&(ns_name, name)) {
+                return false;
+            }
+            visited.push((ns_name, name));
+
+            let def = NamespaceMemberDefinition::iter()
+                .find(|def| def.namespace() == *ns_name && def.name() == *name);
+
+            match def.map(|def| def.type_def()) {
+                Some(crate::TsTypeDef::Interface(inner)) => intersected(&inner)
+                    .into_iter()
+                    .all(|item| is_object_type(item, &mut visited.clone())),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// The `extends` clause and the body of an interface. The type of a struct with flattened fields
+/// is an intersection:
+/// ```ts
+/// export interface Name extends Ns.Flattened { ... }
+/// ```
+/// `None` if a flattened member is not an object type, e.g. an enum. The struct is then declared
+/// as a type alias of the intersection.
+fn interface_body(ty: &TsType) -> Option<(String, TsType)> {
+    let mut fields = Vec::new();
+    let mut extends = Vec::new();
+
+    for item in intersected(ty) {
+        match item {
+            TsType::Object(item_fields) => fields.extend(item_fields.iter().cloned()),
+            TsType::Ref { .. } if is_object_type(item, &mut Vec::new()) => {
+                extends.push(item.to_string())
+            }
+            _ => return None,
+        }
+    }
+
+    let extends = if extends.is_empty() {
+        String::new()
+    } else {
+        format!(" extends {}", extends.join(", "))
+    };
+
+    Some((extends, TsType::Object(fields)))
+}
+
+/// The runtime values of an enum with only unit variants, `Name` is already taken by the schema:
+/// ```ts
+/// export const NameEnum = { "A": "A", "B": "B" } as const;
+/// ```
+/// Native enums are declared before the schema which references them.
+fn enum_declaration(name: &str, schema: &ZodSchema) -> String {
+    let entries = |members: &[EnumMember], sep: &str| {
+        members
+            .iter()
+            .map(|member| {
+                let docs = member
+                    .docs
+                    .as_deref()
+                    .map(|docs| format!("{} ", jsdoc(docs)))
+                    .unwrap_or_default();
+                format!(
+                    "{docs}{}{sep}{}",
+                    string_literal(&member.name),
+                    member.value
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    match schema {
+        ZodSchema::Enum(members) => format!(
+            "export const {name}Enum = {{ {} }} as const;\n",
+            entries(members, ": ")
+        ),
+        ZodSchema::NativeEnum { name, members, .. } => {
+            format!("export enum {name} {{ {} }}\n", entries(members, " = "))
+        }
+        _ => String::new(),
+    }
+}
+
+/// Values of a branded type can't be created with a type assertion, they are parsed instead:
+/// ```ts
+/// export function parseName(value: z.input<typeof Name>): Name { .. }
+/// ```
+fn brand_helper(schema_name: &str, type_name: &str, schema: &ZodSchema) -> String {
+    if !matches!(schema, ZodSchema::Branded(_, _)) {
+        return String::new();
+    }
+
+    format!(
+        "export function parse{type_name}(value: z.input<typeof {schema_name}>): {type_name} {{ return {schema_name}.parse(value) as unknown as {type_name}; }}\n"
+    )
 }
 
 #[async_trait::async_trait]
@@ -67,7 +270,7 @@ pub trait Backend {
 
     fn zod_namespaces() -> HashMap<&'static str, Vec<&'static NamespaceMemberDefinition>> {
         let mut out = HashMap::<&'static str, Vec<&'static NamespaceMemberDefinition>>::new();
-        let members = inventory::iter::<NamespaceMemberDefinition>()
+        let members = NamespaceMemberDefinition::iter()
             .filter(|member| Self::NS_NAMES.contains(&member.namespace()));
 
         for member in members {
@@ -88,49 +291,118 @@ pub trait Backend {
     {
         let rpc_records = inventory::iter::<RpcMember>()
             .filter(|member| Self::NS_NAMES.contains(&member.ns_name()))
-            .map(|m| (m.ns_name(), m.decl()));
+            .map(|m| (m.ns_name(), m.decl_with_options(&options)));
 
         let mut records: BTreeMap<&'static str, String> =
             crate::NamespaceMemberDefinition::collect()
                 .into_iter()
                 .filter(|(ns, _)| Self::NS_NAMES.contains(ns))
                 .map(|(ns, defs)| {
+                    check_unique_names(ns, &defs, &options);
                     (
                         ns,
                         defs.into_iter()
                             .map(|def| {
+                                let generics = def.generics();
+                                let type_params = if generics.is_empty() {
+                                    String::new()
+                                } else {
+                                    format!("<{}>", generics.join(", "))
+                                };
+
                                 let td = match def.type_def() {
                                     crate::TsTypeDef::Interface(inner) => {
-                                        format!(
-                                            "{}export interface {}{}{} {}",
-                                            (def.docs)().unwrap_or_default(),
-                                            options.prefix_interface,
-                                            def.name(),
-                                            options.suffix_interface,
-                                            inner
-                                        )
+                                        let inner = options.apply_to_type(inner);
+                                        match interface_body(&inner) {
+                                            Some((extends, body)) => format!(
+                                                "{}export interface {}{}{}{}{} {}",
+                                                (def.docs)().unwrap_or_default(),
+                                                options.prefix_interface,
+                                                def.name(),
+                                                options.suffix_interface,
+                                                type_params,
+                                                extends,
+                                                body
+                                            ),
+                                            None => format!(
+                                                "{}export type {}{}{}{} = {};",
+                                                (def.docs)().unwrap_or_default(),
+                                                options.prefix_type,
+                                                def.name(),
+                                                options.suffix_type,
+                                                type_params,
+                                                inner
+                                            ),
+                                        }
                                     }
 
                                     crate::TsTypeDef::Type(inner) => {
+                                        let inner = options.apply_to_type(inner);
                                         format!(
-                                            "{}export type {}{}{} = {};",
+                                            "{}export type {}{}{}{} = {};",
                                             (def.docs)().unwrap_or_default(),
                                             options.prefix_type,
                                             def.name(),
                                             options.suffix_type,
+                                            type_params,
                                             inner
                                         )
                                     }
                                 };
 
+                                // generic members are exported as factories which take the schemas
+                                // of their type arguments
+                                let factory = if generics.is_empty() {
+                                    String::new()
+                                } else {
+                                    let params = generics
+                                        .iter()
+                                        .map(|name| format!("{name} extends z.ZodTypeAny"))
+                                        .collect::<Vec<_>>()
+                                        .join(", ");
+
+                                    let args = generics
+                                        .iter()
+                                        .map(|name| format!("{}: {name}", schema_param(name)))
+                                        .collect::<Vec<_>>()
+                                        .join(", ");
+
+                                    format!("<{params}>({args}) => ")
+                                };
+
+                                let schema = options.apply_to_schema(def.schema());
+
+                                let helper = if generics.is_empty() {
+                                    brand_helper(
+                                        &format!(
+                                            "{}{}{}",
+                                            options.prefix_schema,
+                                            def.name(),
+                                            options.suffix_schema
+                                        ),
+                                        &format!(
+                                            "{}{}{}",
+                                            options.prefix_type,
+                                            def.name(),
+                                            options.suffix_type
+                                        ),
+                                        &schema,
+                                    )
+                                } else {
+                                    String::new()
+                                };
+
                                 format!(
-                                    "{}export const {}{}{}= {}\n{}\n\n",
+                                    "{}{}export const {}{}{}= {}{}\n{}\n{}\n",
+                                    enum_declaration(def.name(), &schema),
                                     (def.docs)().unwrap_or_default(),
                                     options.prefix_schema,
                                     def.name(),
                                     options.suffix_schema,
-                                    def.schema(),
-                                    td
+                                    factory,
+                                    schema,
+                                    td,
+                                    helper
                                 )
                             })
                             .collect(),
@@ -0,0 +1,789 @@
+//! Validate JSON values against the schema of a [ZodType] with the semantics of zod.
+//!
+//! This allows to check that the values produced by serde actually pass the generated schema
+//! without running any javascript:
+//!
+//! ```
+//! # use zod_core::validate::assert_conforms;
+//! assert_conforms::<Vec<Option<u8>>>(&vec![Some(1), None]);
+//! ```
+//!
+//! References to namespace members (`z.lazy(() => Ns.Name)`) are resolved through the inventory
+//! of registered members. Objects accept unknown keys like `z.object` does by default.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::ast::{
+    integer_checks, integer_pattern, schema_param, IntegerMode, IpVersion, Literal, NumberCheck,
+    Numeric, Refinement, StringCheck, UnknownKeys, ZodObject, ZodSchema,
+};
+use crate::validation::{join, Check, PathSegment, ValidationErrors};
+use crate::{NamespaceMemberDefinition, ZodType};
+
+/// validate a serialized value against the schema of `T`
+pub fn validate<T: ZodType + ?Sized>(value: &Value) -> Result<(), ValidationErrors> {
+    validate_schema(&T::schema(), value)
+}
+
+/// validate a value which is deserialized into `T` against its input schema, see
+/// [ZodType::input_schema]
+pub fn validate_input<T: ZodType + ?Sized>(value: &Value) -> Result<(), ValidationErrors> {
+    validate_schema(&T::input_schema(), value)
+}
+
+pub fn validate_schema(schema: &ZodSchema, value: &Value) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::default();
+    check(schema, Some(value), &[], &mut errors);
+    errors.into_result()
+}
+
+/// Serialize `value` with serde and panic if the result does not conform to the schema of `T`.
+///
+/// # Panics
+/// If `value` cannot be serialized or does not conform to the schema.
+#[track_caller]
+pub fn assert_conforms<T: ZodType + ?Sized>(value: &impl serde::Serialize) {
+    let json = serde_json::to_value(value).expect("value serializes to json");
+
+    if let Err(errors) = validate::<T>(&json) {
+        panic!(
+            "value does not conform to the schema\n  value: {json}\n schema: {}\n errors: {errors}",
+            T::schema()
+        )
+    }
+}
+
+/// Validate `value` at `path`. A value of `None` is missing, ie. `undefined` in javascript.
+fn check(
+    schema: &ZodSchema,
+    value: Option<&Value>,
+    path: &[PathSegment],
+    errors: &mut ValidationErrors,
+) {
+    let schema = schema.undescribed();
+    let value = match (schema, value) {
+        (
+            ZodSchema::Optional(_)
+            | ZodSchema::Nullish(_)
+            | ZodSchema::Defaulted(_, _)
+            | ZodSchema::Unknown,
+            None,
+        ) => return,
+        (ZodSchema::Nullable(_) | ZodSchema::Nullish(_), Some(Value::Null)) => return,
+        (ZodSchema::Raw(_), _) => return,
+        (_, None) => return errors.push(path, "Required"),
+        (_, Some(value)) => value,
+    };
+
+    match schema {
+        ZodSchema::String(checks) => match value {
+            Value::String(s) => {
+                for check in checks {
+                    if let Some(message) = check_string(s, check) {
+                        errors.push(path, message)
+                    }
+                }
+            }
+            other => errors.push(path, expected("string", other)),
+        },
+
+        ZodSchema::Number(checks) => match value {
+            Value::Number(n) => {
+                for check in checks {
+                    if let Some(message) = check_number(n, check) {
+                        errors.push(path, message)
+                    }
+                }
+            }
+            other => errors.push(path, expected("number", other)),
+        },
+
+        ZodSchema::Integer { min, max, mode } => match mode {
+            IntegerMode::Number => check(
+                &ZodSchema::Number(integer_checks(*min, *max)),
+                Some(value),
+                path,
+                errors,
+            ),
+            IntegerMode::String => check(
+                &ZodSchema::String(vec![StringCheck::Regex(String::from(integer_pattern(
+                    *min,
+                )))]),
+                Some(value),
+                path,
+                errors,
+            ),
+            IntegerMode::BigInt => match parse_bigint(value) {
+                Some(n) if compare_ints(n, *min) == Ordering::Less => errors.push(
+                    path,
+                    format!("BigInt must be greater than or equal to {min}"),
+                ),
+                Some(n) if compare_ints(n, *max) == Ordering::Greater => {
+                    errors.push(path, format!("BigInt must be less than or equal to {max}"))
+                }
+                Some(_) => {}
+                None => errors.push(path, expected("bigint", value)),
+            },
+        },
+
+        ZodSchema::Boolean => {
+            if !value.is_boolean() {
+                errors.push(path, expected("boolean", value))
+            }
+        }
+
+        ZodSchema::Null => {
+            if !value.is_null() {
+                errors.push(path, expected("null", value))
+            }
+        }
+
+        ZodSchema::Never => errors.push(path, expected("never", value)),
+
+        ZodSchema::Unknown | ZodSchema::Raw(_) => {}
+
+        ZodSchema::Enum(members) | ZodSchema::NativeEnum { members, .. } => {
+            if !members
+                .iter()
+                .any(|member| &literal_value(&member.value) == value)
+            {
+                let expected = members
+                    .iter()
+                    .map(|member| match &member.value {
+                        Literal::String(s) => format!("'{s}'"),
+                        Literal::Int(n) => n.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" | ");
+
+                errors.push(
+                    path,
+                    format!("Invalid enum value. Expected {expected}, received {value}"),
+                )
+            }
+        }
+
+        ZodSchema::Literal(expected) => {
+            if &literal_value(expected) != value {
+                errors.push(path, format!("Invalid literal value, expected {expected}"))
+            }
+        }
+
+        ZodSchema::Array(inner) => match value {
+            Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    check(inner, Some(item), &join(path, index), errors)
+                }
+            }
+            other => errors.push(path, expected("array", other)),
+        },
+
+        ZodSchema::Record(key, inner) => match value {
+            Value::Object(map) => {
+                for (k, v) in map {
+                    let path = join(path, k.as_str());
+                    check(key, Some(&Value::String(k.clone())), &path, errors);
+                    check(inner, Some(v), &path, errors);
+                }
+            }
+            other => errors.push(path, expected("object", other)),
+        },
+
+        ZodSchema::Tuple(items) => match value {
+            Value::Array(values) => match values.len().cmp(&items.len()) {
+                Ordering::Less => errors.push(
+                    path,
+                    format!("Array must contain at least {} element(s)", items.len()),
+                ),
+                Ordering::Greater => errors.push(
+                    path,
+                    format!("Array must contain at most {} element(s)", items.len()),
+                ),
+                Ordering::Equal => {
+                    for (index, (item, value)) in items.iter().zip(values).enumerate() {
+                        check(item, Some(value), &join(path, index), errors)
+                    }
+                }
+            },
+            other => errors.push(path, expected("array", other)),
+        },
+
+        ZodSchema::Object(obj) => check_object(obj, value, path, errors),
+
+        ZodSchema::Union(variants) => {
+            let valid = variants.iter().any(|variant| {
+                let mut variant_errors = ValidationErrors::default();
+                check(variant, Some(value), path, &mut variant_errors);
+                variant_errors.is_empty()
+            });
+
+            if !valid {
+                errors.push(path, "Invalid input")
+            }
+        }
+
+        ZodSchema::DiscriminatedUnion { tag, variants } => {
+            let discriminator = value.get(tag).and_then(Value::as_str);
+
+            let variant = variants.iter().find(|variant| {
+                discriminator.is_some_and(|d| discriminators(variant, tag).contains(&d))
+            });
+
+            match variant {
+                Some(variant) => check(variant, Some(value), path, errors),
+                None if !value.is_object() => errors.push(path, expected("object", value)),
+                None => {
+                    let options = variants
+                        .iter()
+                        .flat_map(|variant| discriminators(variant, tag))
+                        .map(|option| format!("'{option}'"))
+                        .collect::<Vec<_>>();
+
+                    errors.push(
+                        &join(path, tag.as_str()),
+                        format!(
+                            "Invalid discriminator value. Expected {}",
+                            options.join(" | ")
+                        ),
+                    )
+                }
+            }
+        }
+
+        ZodSchema::Lazy {
+            ns_name,
+            name,
+            args,
+        } => match resolve(ns_name, name, args) {
+            Some(schema) => check(&schema, Some(value), path, errors),
+            None => errors.push(path, format!("Unknown reference {ns_name}.{name}")),
+        },
+
+        ZodSchema::Optional(inner)
+        | ZodSchema::Nullable(inner)
+        | ZodSchema::Nullish(inner)
+        | ZodSchema::Defaulted(inner, _)
+        | ZodSchema::Described(inner, _)
+        | ZodSchema::Branded(inner, _) => check(inner, Some(value), path, errors),
+
+        ZodSchema::Refined(inner, refinements) => {
+            let mut inner_errors = ValidationErrors::default();
+            check(inner, Some(value), path, &mut inner_errors);
+
+            if inner_errors.is_empty() {
+                for refinement in refinements {
+                    if let Some(message) = check_refinement(value, refinement) {
+                        errors.push(path, message)
+                    }
+                }
+            } else {
+                errors.extend(inner_errors)
+            }
+        }
+    }
+}
+
+/// Extended members are checked against the same value, unknown keys are stripped by zod.
+fn check_object(
+    obj: &ZodObject,
+    value: &Value,
+    path: &[PathSegment],
+    errors: &mut ValidationErrors,
+) {
+    let Value::Object(map) = value else {
+        return errors.push(path, expected("object", value));
+    };
+
+    // aliased keys are renamed like the `z.preprocess` of the rendered schema does
+    if obj.aliases().next().is_some() {
+        let renamed = map
+            .iter()
+            .map(|(key, value)| {
+                let key = obj
+                    .aliases()
+                    .find(|(alias, _)| alias == key)
+                    .map_or(key.as_str(), |(_, name)| name);
+
+                (String::from(key), value.clone())
+            })
+            .collect();
+
+        let mut obj = obj.clone();
+        for field in obj.fields.iter_mut() {
+            field.aliases.clear();
+        }
+
+        return check_object(&obj, &Value::Object(renamed), path, errors);
+    }
+
+    for field in obj.fields.iter() {
+        check(
+            &field.schema,
+            map.get(&field.name),
+            &join(path, field.name.as_str()),
+            errors,
+        )
+    }
+
+    // a flattened `Option<T>` is `None` if the fields of `T` do not match
+    for ext in obj
+        .extends
+        .iter()
+        .filter(|ext| ZodObject::optional_extension(ext).is_none())
+    {
+        check(ext, Some(value), path, errors)
+    }
+
+    if obj.catchall.is_none() && obj.unknown_keys != UnknownKeys::Strict {
+        return;
+    }
+
+    let known = known_keys(obj);
+    let unknown = map.iter().filter(|(key, _)| !known.contains(key));
+
+    match &obj.catchall {
+        Some(catchall) => {
+            for (key, value) in unknown {
+                check(catchall, Some(value), &join(path, key.as_str()), errors)
+            }
+        }
+        None => {
+            let keys = unknown
+                .map(|(key, _)| format!("'{key}'"))
+                .collect::<Vec<_>>();
+
+            if !keys.is_empty() {
+                errors.push(
+                    path,
+                    format!("Unrecognized key(s) in object: {}", keys.join(", ")),
+                )
+            }
+        }
+    }
+}
+
+/// the keys of an object including the keys of its flattened members
+pub(crate) fn known_keys(obj: &ZodObject) -> Vec<String> {
+    let mut keys = obj
+        .fields
+        .iter()
+        .map(|field| field.name.clone())
+        .collect::<Vec<_>>();
+
+    for ext in obj.extends.iter() {
+        let ext = ZodObject::optional_extension(ext).unwrap_or(ext);
+
+        match ext {
+            ZodSchema::Object(ext) => keys.extend(known_keys(ext)),
+            ZodSchema::Lazy {
+                ns_name,
+                name,
+                args,
+            } => {
+                if let Some(ZodSchema::Object(ext)) = resolve(ns_name, name, args) {
+                    keys.extend(known_keys(&ext))
+                }
+            }
+            _ => {}
+        }
+    }
+
+    keys
+}
+
+fn check_string(s: &str, check: &StringCheck) -> Option<String> {
+    match check {
+        StringCheck::Length(n) if s.encode_utf16().count() != *n => {
+            Some(format!("String must contain exactly {n} character(s)"))
+        }
+        StringCheck::Ip(version) => {
+            let valid = match version {
+                None => s.parse::<std::net::IpAddr>().is_ok(),
+                Some(IpVersion::V4) => s.parse::<std::net::Ipv4Addr>().is_ok(),
+                Some(IpVersion::V6) => s.parse::<std::net::Ipv6Addr>().is_ok(),
+            };
+
+            (!valid).then(|| String::from("Invalid ip"))
+        }
+        StringCheck::Regex(pattern) => s.check(&Refinement::Regex(pattern.clone())),
+        _ => None,
+    }
+}
+
+fn check_number(n: &serde_json::Number, check: &NumberCheck) -> Option<String> {
+    match check {
+        // json numbers are always finite
+        NumberCheck::Finite => None,
+        NumberCheck::Int => {
+            let int =
+                n.is_i64() || n.is_u64() || n.as_f64().map(|f| f.fract() == 0.0) == Some(true);
+            (!int).then(|| String::from("Expected integer, received float"))
+        }
+        NumberCheck::Nonnegative => (compare(n, &Numeric::Signed(0)) == Ordering::Less)
+            .then(|| String::from("Number must be greater than or equal to 0")),
+        NumberCheck::Gte(min) => (compare(n, min) == Ordering::Less)
+            .then(|| format!("Number must be greater than or equal to {min}")),
+        NumberCheck::Lte(max) => (compare(n, max) == Ordering::Greater)
+            .then(|| format!("Number must be less than or equal to {max}")),
+    }
+}
+
+/// compare integers exactly and everything else as floats
+fn compare(n: &serde_json::Number, bound: &Numeric) -> Ordering {
+    let int = n
+        .as_i64()
+        .map(i128::from)
+        .or_else(|| n.as_u64().map(i128::from));
+
+    match (int, bound) {
+        (Some(n), Numeric::Signed(bound)) => n.cmp(bound),
+        (Some(n), Numeric::Unsigned(bound)) => match i128::try_from(*bound) {
+            Ok(bound) => n.cmp(&bound),
+            Err(_) => Ordering::Less,
+        },
+        _ => {
+            let n = n.as_f64().unwrap_or(f64::NAN);
+            n.partial_cmp(&bound.as_f64()).unwrap_or(Ordering::Equal)
+        }
+    }
+}
+
+/// the JSON value of a literal
+pub(crate) fn literal_value(literal: &Literal) -> Value {
+    match literal {
+        Literal::String(s) => Value::String(s.clone()),
+        Literal::Int(n) => i64::try_from(*n)
+            .map(Value::from)
+            .or_else(|_| u64::try_from(*n).map(Value::from))
+            .unwrap_or_else(|_| Value::from(*n as f64)),
+    }
+}
+
+/// the value `z.coerce.bigint()` produces from a decimal string or an integer
+fn parse_bigint(value: &Value) -> Option<Numeric> {
+    match value {
+        Value::String(s) => s
+            .parse::<i128>()
+            .map(Numeric::Signed)
+            .or_else(|_| s.parse::<u128>().map(Numeric::Unsigned))
+            .ok(),
+        Value::Number(n) => n
+            .as_i64()
+            .map(Numeric::from)
+            .or_else(|| n.as_u64().map(Numeric::from)),
+        _ => None,
+    }
+}
+
+/// compare two integers exactly
+fn compare_ints(a: Numeric, b: Numeric) -> Ordering {
+    match (a, b) {
+        (Numeric::Signed(a), Numeric::Signed(b)) => a.cmp(&b),
+        (Numeric::Unsigned(a), Numeric::Unsigned(b)) => a.cmp(&b),
+        (Numeric::Signed(a), Numeric::Unsigned(b)) => match u128::try_from(a) {
+            Ok(a) => a.cmp(&b),
+            Err(_) => Ordering::Less,
+        },
+        (Numeric::Unsigned(_), Numeric::Signed(_)) => compare_ints(b, a).reverse(),
+        (a, b) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+    }
+}
+
+pub(crate) fn check_refinement(value: &Value, refinement: &Refinement) -> Option<String> {
+    match value {
+        Value::String(s) => s.check(refinement),
+        Value::Number(n) => n.as_f64().and_then(|n| n.check(refinement)),
+        Value::Array(items) => items.check(refinement),
+        Value::Object(map) => crate::validation::check_length(map.len(), refinement),
+        Value::Null | Value::Bool(_) => None,
+    }
+}
+
+/// the accepted values of the literal or enum field `tag` of an object variant
+fn discriminators<'a>(schema: &'a ZodSchema, tag: &str) -> Vec<&'a str> {
+    let ZodSchema::Object(obj) = schema.undescribed() else {
+        return Vec::new();
+    };
+
+    let field = obj.fields.iter().find(|field| field.name == tag);
+
+    match field.map(|field| &field.schema) {
+        Some(ZodSchema::Literal(Literal::String(s))) => vec![s.as_str()],
+        Some(ZodSchema::Enum(members)) => members
+            .iter()
+            .filter_map(|member| match &member.value {
+                Literal::String(s) => Some(s.as_str()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// The schema of a namespace member with its generic parameters replaced by `args`
+pub(crate) fn resolve(ns_name: &str, name: &str, args: &[ZodSchema]) -> Option<ZodSchema> {
+    let member = NamespaceMemberDefinition::iter()
+        .find(|member| member.namespace() == ns_name && member.name() == name)?;
+
+    let params = member
+        .generics()
+        .iter()
+        .map(|name| schema_param(name))
+        .zip(args.iter())
+        .collect::<HashMap<_, _>>();
+
+    let schema = member.schema();
+
+    if params.is_empty() {
+        Some(schema)
+    } else {
+        Some(substitute(schema, &params))
+    }
+}
+
+/// replace the placeholders of generic parameters, see [NamespaceMemberDefinition::generics]
+fn substitute(schema: ZodSchema, params: &HashMap<String, &ZodSchema>) -> ZodSchema {
+    let sub = |schema: ZodSchema| substitute(schema, params);
+    let sub_box = |schema: Box<ZodSchema>| Box::new(substitute(*schema, params));
+
+    match schema {
+        ZodSchema::Raw(name) => match params.get(name.as_str()) {
+            Some(arg) => (*arg).clone(),
+            None => ZodSchema::Raw(name),
+        },
+        ZodSchema::Array(inner) => ZodSchema::Array(sub_box(inner)),
+        ZodSchema::Record(k, v) => ZodSchema::Record(sub_box(k), sub_box(v)),
+        ZodSchema::Tuple(items) => ZodSchema::Tuple(items.into_iter().map(sub).collect()),
+        ZodSchema::Object(obj) => ZodSchema::Object(ZodObject {
+            fields: obj
+                .fields
+                .into_iter()
+                .map(|mut field| {
+                    field.schema = sub(field.schema);
+                    field
+                })
+                .collect(),
+            extends: obj.extends.into_iter().map(sub).collect(),
+            catchall: obj.catchall.map(sub_box),
+            ..obj
+        }),
+        ZodSchema::Union(variants) => ZodSchema::Union(variants.into_iter().map(sub).collect()),
+        ZodSchema::DiscriminatedUnion { tag, variants } => ZodSchema::DiscriminatedUnion {
+            tag,
+            variants: variants.into_iter().map(sub).collect(),
+        },
+        ZodSchema::Lazy {
+            ns_name,
+            name,
+            args,
+        } => ZodSchema::Lazy {
+            ns_name,
+            name,
+            args: args.into_iter().map(sub).collect(),
+        },
+        ZodSchema::Optional(inner) => ZodSchema::Optional(sub_box(inner)),
+        ZodSchema::Nullable(inner) => ZodSchema::Nullable(sub_box(inner)),
+        ZodSchema::Nullish(inner) => ZodSchema::Nullish(sub_box(inner)),
+        ZodSchema::Defaulted(inner, value) => ZodSchema::Defaulted(sub_box(inner), value),
+        ZodSchema::Refined(inner, refinements) => ZodSchema::Refined(sub_box(inner), refinements),
+        ZodSchema::Described(inner, description) => {
+            ZodSchema::Described(sub_box(inner), description)
+        }
+        ZodSchema::Branded(inner, name) => ZodSchema::Branded(sub_box(inner), name),
+        other @ (ZodSchema::String(_)
+        | ZodSchema::Number(_)
+        | ZodSchema::Integer { .. }
+        | ZodSchema::Boolean
+        | ZodSchema::Null
+        | ZodSchema::Never
+        | ZodSchema::Unknown
+        | ZodSchema::Literal(_)
+        | ZodSchema::Enum(_)
+        | ZodSchema::NativeEnum { .. }) => other,
+    }
+}
+
+fn expected(expected: &str, received: &Value) -> String {
+    let received = match received {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    };
+
+    format!("Expected {expected}, received {received}")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ast::ZodField;
+    use serde_json::json;
+
+    fn errors(schema: &ZodSchema, value: Value) -> String {
+        validate_schema(schema, &value)
+            .err()
+            .map(|errors| errors.to_string())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn primitives() {
+        assert!(validate::<String>(&json!("abc")).is_ok());
+        assert!(validate::<u8>(&json!(255)).is_ok());
+        assert!(validate::<u8>(&json!(256)).is_err());
+        assert!(validate::<i8>(&json!(-1)).is_ok());
+        assert!(validate::<u8>(&json!(-1)).is_err());
+        assert!(validate::<u8>(&json!(1.5)).is_err());
+        assert!(validate::<f32>(&json!(1.5)).is_ok());
+        assert!(validate::<bool>(&json!(true)).is_ok());
+        assert!(validate::<std::net::Ipv4Addr>(&json!("127.0.0.1")).is_ok());
+        assert!(validate::<std::net::Ipv4Addr>(&json!("::1")).is_err());
+        assert!(validate::<char>(&json!("ab")).is_err());
+        assert!(validate::<u64>(&json!(u64::MAX)).is_ok());
+
+        assert_eq!(
+            errors(&String::schema(), json!(1)),
+            "Expected string, received number"
+        );
+    }
+
+    #[test]
+    fn wide_integers() {
+        let bigint = u128::schema().with_integer_mode(IntegerMode::BigInt);
+        assert!(validate_schema(&bigint, &json!(u128::MAX.to_string())).is_ok());
+        assert!(validate_schema(&bigint, &json!(1)).is_ok());
+        assert_eq!(
+            errors(&bigint, json!("-1")),
+            "BigInt must be greater than or equal to 0"
+        );
+        assert_eq!(
+            errors(&bigint, json!("a")),
+            "Expected bigint, received string"
+        );
+
+        let string = i64::schema().with_integer_mode(IntegerMode::String);
+        assert!(validate_schema(&string, &json!("-1")).is_ok());
+        assert!(validate_schema(&string, &json!(-1)).is_err());
+    }
+
+    #[test]
+    fn containers() {
+        assert_conforms::<Vec<Option<u8>>>(&vec![Some(1), None]);
+        assert_conforms::<(String, bool)>(&("a", true));
+        assert_conforms::<std::collections::HashMap<u8, bool>>(&json!({ "1": true }));
+        assert_conforms::<Result<u8, String>>(&Ok::<_, String>(1));
+
+        assert_eq!(
+            errors(
+                &<std::collections::HashMap<u8, bool>>::schema(),
+                json!({ "a": 1 })
+            ),
+            "a: must match /^\\d+$/, a: Expected boolean, received number"
+        );
+        assert_eq!(
+            errors(&<(u8, u8)>::schema(), json!([1])),
+            "Array must contain at least 2 element(s)"
+        );
+        assert_eq!(
+            errors(&<Vec<bool>>::schema(), json!([true, 1])),
+            "[1]: Expected boolean, received number"
+        );
+    }
+
+    #[test]
+    fn objects() {
+        let schema = ZodSchema::object(vec![
+            ZodField::new("a", ZodSchema::Boolean),
+            ZodField::new("b", ZodSchema::Boolean.optional()),
+            ZodField::new("c", ZodSchema::Boolean.nullable()),
+        ]);
+
+        assert_eq!(errors(&schema, json!({ "a": true, "c": null, "x": 1 })), "");
+        assert_eq!(
+            errors(&schema, json!({ "b": null, "c": true })),
+            "a: Required, b: Expected boolean, received null"
+        );
+    }
+
+    #[test]
+    fn unknown_keys() {
+        let object = || ZodObject::new(vec![ZodField::new("a", ZodSchema::Boolean)]);
+
+        let strict = object().with_unknown_keys(UnknownKeys::Strict).into();
+        assert_eq!(errors(&strict, json!({ "a": true })), "");
+        assert_eq!(
+            errors(&strict, json!({ "a": true, "x": 1, "y": 2 })),
+            "Unrecognized key(s) in object: 'x', 'y'"
+        );
+
+        let catchall = object()
+            .extend(<std::collections::HashMap<String, u8>>::schema())
+            .into();
+        assert_eq!(errors(&catchall, json!({ "a": true, "x": 1 })), "");
+        assert_eq!(
+            errors(&catchall, json!({ "a": true, "x": "1" })),
+            "x: Expected number, received string"
+        );
+    }
+
+    #[test]
+    fn discriminated_unions() {
+        let variant = |tag: &str| {
+            ZodSchema::object(vec![
+                ZodField::new("type", ZodSchema::literal(tag)),
+                ZodField::new("value", ZodSchema::Boolean),
+            ])
+        };
+
+        let schema = ZodSchema::DiscriminatedUnion {
+            tag: String::from("type"),
+            variants: vec![variant("A"), variant("B")],
+        };
+
+        assert_eq!(errors(&schema, json!({ "type": "B", "value": true })), "");
+        assert_eq!(errors(&schema, json!({ "type": "B" })), "value: Required");
+        assert_eq!(
+            errors(&schema, json!({ "type": "C" })),
+            "type: Invalid discriminator value. Expected 'A' | 'B'"
+        );
+    }
+
+    #[test]
+    fn refinements() {
+        let schema = ZodSchema::String(Vec::new())
+            .refine(Refinement::MinLength(2))
+            .refine(Refinement::Email)
+            .nullable();
+
+        assert_eq!(errors(&schema, json!(null)), "");
+        assert_eq!(errors(&schema, json!("a@b.io")), "");
+        assert_eq!(
+            errors(&schema, json!("a")),
+            "must contain at least 2 character(s), invalid email"
+        );
+    }
+
+    #[test]
+    fn generic_references() {
+        let schema = ZodSchema::object(vec![ZodField::new(
+            "value",
+            ZodSchema::Raw(schema_param("T")),
+        )]);
+
+        let params = HashMap::from([(schema_param("T"), &ZodSchema::Boolean)]);
+
+        assert_eq!(
+            substitute(schema, &params),
+            ZodSchema::object(vec![ZodField::new("value", ZodSchema::Boolean)])
+        );
+    }
+}
@@ -0,0 +1,561 @@
+//! Rust side enforcement of the refinements declared with `#[zod(...)]`.
+//!
+//! Types deriving `Zod` with `#[zod(validate)]` implement [Validate] and check the same rules as
+//! the generated schema, eg. `#[zod(min_length = 3)]` or `#[zod(email)]`. Errors carry the path of
+//! the offending value as it appears in JSON.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::{self, Display};
+
+use crate::ast::{Numeric, Refinement};
+
+pub trait Validate {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        self.validate_at(&[], &mut errors);
+        errors.into_result()
+    }
+
+    /// collect the errors of `self` located at `path`
+    fn validate_at(&self, path: &[PathSegment], errors: &mut ValidationErrors);
+}
+
+/// A key or index of the JSON path to an invalid value
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "rpc", derive(serde::Serialize), serde(untagged))]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+impl From<&str> for PathSegment {
+    fn from(value: &str) -> Self {
+        Self::Key(value.to_owned())
+    }
+}
+
+impl From<usize> for PathSegment {
+    fn from(value: usize) -> Self {
+        Self::Index(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "rpc", derive(serde::Serialize))]
+pub struct ValidationError {
+    pub path: Vec<PathSegment>,
+    pub message: String,
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.path.iter().enumerate() {
+            match segment {
+                PathSegment::Key(key) if i == 0 => write!(f, "{key}")?,
+                PathSegment::Key(key) => write!(f, ".{key}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, ": {}", self.message)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "rpc", derive(serde::Serialize), serde(transparent))]
+pub struct ValidationErrors(Vec<ValidationError>);
+
+impl ValidationErrors {
+    pub fn push(&mut self, path: &[PathSegment], message: impl Into<String>) {
+        self.0.push(ValidationError {
+            path: path.to_vec(),
+            message: message.into(),
+        })
+    }
+
+    pub fn extend(&mut self, other: ValidationErrors) {
+        self.0.extend(other.0)
+    }
+
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// Values which can be checked against a [Refinement]. `#[derive(Zod)]` rejects refinements which
+/// don't apply to the type of a field, eg. `email` on a number.
+pub trait Check {
+    /// the error message if the value violates the refinement
+    fn check(&self, refinement: &Refinement) -> Option<String>;
+}
+
+#[doc(hidden)]
+pub fn check<T: Check + ?Sized>(
+    value: &T,
+    refinement: &Refinement,
+    path: &[PathSegment],
+    errors: &mut ValidationErrors,
+) {
+    if let Some(message) = value.check(refinement) {
+        errors.push(path, message)
+    }
+}
+
+pub(crate) fn check_length(len: usize, refinement: &Refinement) -> Option<String> {
+    match refinement {
+        Refinement::MinLength(min) if len < *min => {
+            Some(format!("must contain at least {min} element(s)"))
+        }
+        Refinement::MaxLength(max) if len > *max => {
+            Some(format!("must contain at most {max} element(s)"))
+        }
+        Refinement::NonEmpty if len == 0 => Some(String::from("must not be empty")),
+        _ => None,
+    }
+}
+
+impl Check for str {
+    fn check(&self, refinement: &Refinement) -> Option<String> {
+        // javascript counts utf-16 code units
+        let len = self.encode_utf16().count();
+
+        match refinement {
+            Refinement::MinLength(min) if len < *min => {
+                Some(format!("must contain at least {min} character(s)"))
+            }
+            Refinement::MaxLength(max) if len > *max => {
+                Some(format!("must contain at most {max} character(s)"))
+            }
+            Refinement::NonEmpty if len == 0 => Some(String::from("must not be empty")),
+            Refinement::Regex(pattern) if !is_match(pattern, self) => {
+                Some(format!("must match /{pattern}/"))
+            }
+            Refinement::Email if !is_email(self) => Some(String::from("invalid email")),
+            Refinement::Url if !is_url(self) => Some(String::from("invalid url")),
+            _ => None,
+        }
+    }
+}
+
+impl Check for String {
+    fn check(&self, refinement: &Refinement) -> Option<String> {
+        self.as_str().check(refinement)
+    }
+}
+
+/// The email pattern of zod without its lookaheads, which the `regex` crate doesn't support:
+/// `/^(?!\.)(?!.*\.\.)([A-Z0-9_'+\-\.]*)[A-Z0-9_+-]@([A-Z0-9][A-Z0-9\-]*\.)+[A-Z]{2,}$/i`
+const EMAIL: &str = r"(?i)^([A-Z0-9_'+\-\.]*)[A-Z0-9_+-]@([A-Z0-9][A-Z0-9\-]*\.)+[A-Z]{2,}$";
+
+fn is_email(value: &str) -> bool {
+    !value.starts_with('.') && !value.contains("..") && is_match(EMAIL, value)
+}
+
+/// zod accepts every url the `URL` constructor parses, the `url` crate implements the same
+/// WHATWG parser
+fn is_url(value: &str) -> bool {
+    url::Url::parse(value).is_ok()
+}
+
+thread_local! {
+    static REGEX_CACHE: RefCell<HashMap<String, regex::Regex>> = RefCell::default();
+}
+
+/// Patterns which are not valid rust regular expressions never match.
+fn is_match(pattern: &str, value: &str) -> bool {
+    REGEX_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+
+        if !cache.contains_key(pattern) {
+            match regex::Regex::new(&js_regex(pattern)) {
+                Ok(regex) => cache.insert(pattern.to_owned(), regex),
+                Err(_) => return false,
+            };
+        }
+
+        cache[pattern].is_match(value)
+    })
+}
+
+/// Translate the classes which only match ascii in javascript, eg. `\d`, into rust syntax
+pub(crate) fn js_regex(pattern: &str) -> String {
+    let mut out = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('d') => out.push_str("[0-9]"),
+            Some('D') => out.push_str("[^0-9]"),
+            Some('w') => out.push_str("[0-9A-Za-z_]"),
+            Some('W') => out.push_str("[^0-9A-Za-z_]"),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+
+    out
+}
+
+macro_rules! impl_number_check {
+    ($($t:ty),*) => {
+        $(impl Check for $t {
+            fn check(&self, refinement: &Refinement) -> Option<String> {
+                let value = Numeric::from(*self);
+
+                match refinement {
+                    Refinement::Min(min) if value.as_f64() < min.as_f64() => {
+                        Some(format!("must be greater than or equal to {min}"))
+                    }
+                    Refinement::Max(max) if value.as_f64() > max.as_f64() => {
+                        Some(format!("must be less than or equal to {max}"))
+                    }
+                    _ => None,
+                }
+            }
+        })*
+    };
+}
+
+impl_number_check!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+macro_rules! impl_collection_check {
+    ($(impl<$($generic:ident),*> for $t:ty);* $(;)?) => {
+        $(impl<$($generic),*> Check for $t {
+            fn check(&self, refinement: &Refinement) -> Option<String> {
+                check_length(self.len(), refinement)
+            }
+        })*
+    };
+}
+
+impl_collection_check!(
+    impl<T> for [T];
+    impl<T> for Vec<T>;
+    impl<T> for HashSet<T>;
+    impl<T> for BTreeSet<T>;
+);
+
+impl<T, const N: usize> Check for [T; N] {
+    fn check(&self, refinement: &Refinement) -> Option<String> {
+        check_length(N, refinement)
+    }
+}
+
+/// `None` satisfies every refinement
+impl<T: Check> Check for Option<T> {
+    fn check(&self, refinement: &Refinement) -> Option<String> {
+        self.as_ref().and_then(|value| value.check(refinement))
+    }
+}
+
+macro_rules! impl_wrapper_check {
+    ($(impl<T> for $t:ty);* $(;)?) => {
+        $(impl<T: Check + ?Sized> Check for $t {
+            fn check(&self, refinement: &Refinement) -> Option<String> {
+                T::check(self, refinement)
+            }
+        })*
+    };
+}
+
+impl_wrapper_check!(
+    impl<T> for Box<T>;
+    impl<T> for std::sync::Arc<T>;
+    impl<T> for std::rc::Rc<T>;
+    impl<T> for &T;
+);
+
+pub(crate) fn join(path: &[PathSegment], segment: impl Into<PathSegment>) -> Vec<PathSegment> {
+    let mut path = path.to_vec();
+    path.push(segment.into());
+    path
+}
+
+macro_rules! impl_wrapper_validate {
+    ($(impl<T> for $t:ty);* $(;)?) => {
+        $(impl<T: Validate + ?Sized> Validate for $t {
+            fn validate_at(&self, path: &[PathSegment], errors: &mut ValidationErrors) {
+                T::validate_at(self, path, errors)
+            }
+        })*
+    };
+}
+
+impl_wrapper_validate!(
+    impl<T> for Box<T>;
+    impl<T> for std::sync::Arc<T>;
+    impl<T> for std::rc::Rc<T>;
+    impl<T> for &T;
+);
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_at(&self, path: &[PathSegment], errors: &mut ValidationErrors) {
+        if let Some(value) = self {
+            value.validate_at(path, errors)
+        }
+    }
+}
+
+macro_rules! impl_sequence_validate {
+    ($(impl<T> for $t:ty);* $(;)?) => {
+        $(impl<T: Validate> Validate for $t {
+            fn validate_at(&self, path: &[PathSegment], errors: &mut ValidationErrors) {
+                for (index, item) in self.iter().enumerate() {
+                    item.validate_at(&join(path, index), errors)
+                }
+            }
+        })*
+    };
+}
+
+impl_sequence_validate!(
+    impl<T> for [T];
+    impl<T> for Vec<T>;
+    impl<T> for HashSet<T>;
+    impl<T> for BTreeSet<T>;
+);
+
+impl<T: Validate, const N: usize> Validate for [T; N] {
+    fn validate_at(&self, path: &[PathSegment], errors: &mut ValidationErrors) {
+        self.as_slice().validate_at(path, errors)
+    }
+}
+
+macro_rules! impl_map_validate {
+    ($($t:ident),*) => {
+        $(impl<K: Display, V: Validate> Validate for $t<K, V> {
+            fn validate_at(&self, path: &[PathSegment], errors: &mut ValidationErrors) {
+                for (key, value) in self.iter() {
+                    value.validate_at(&join(path, key.to_string().as_str()), errors)
+                }
+            }
+        })*
+    };
+}
+
+impl_map_validate!(HashMap, BTreeMap);
+
+/// Leaf values have no nested refinements. These impls let generic types which are bound by
+/// [Validate] be instantiated with primitives, e.g. `Page<String>`.
+macro_rules! impl_leaf_validate {
+    ($($t:ty),*) => {
+        $(impl Validate for $t {
+            fn validate_at(&self, _: &[PathSegment], _: &mut ValidationErrors) {}
+        })*
+    };
+}
+
+impl_leaf_validate!(
+    String,
+    str,
+    bool,
+    char,
+    (),
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64
+);
+
+#[doc(hidden)]
+pub mod __private {
+    //! Autoref based dispatch which validates a value if its type implements [Validate] and
+    //! does nothing otherwise. Used by the generated code for field values and rpc arguments.
+    //!
+    //! ```ignore
+    //! use zod::validation::__private::{ViaNoop as _, ViaValidate as _};
+    //! (&Probe(&value)).validate_at(path, errors);
+    //! ```
+    use super::{PathSegment, Validate, ValidationErrors};
+
+    pub struct Probe<'a, T: ?Sized>(pub &'a T);
+
+    pub trait ViaValidate {
+        fn validate_at(&self, path: &[PathSegment], errors: &mut ValidationErrors);
+    }
+
+    impl<T: Validate + ?Sized> ViaValidate for Probe<'_, T> {
+        fn validate_at(&self, path: &[PathSegment], errors: &mut ValidationErrors) {
+            self.0.validate_at(path, errors)
+        }
+    }
+
+    pub trait ViaNoop {
+        fn validate_at(&self, path: &[PathSegment], errors: &mut ValidationErrors);
+    }
+
+    impl<T: ?Sized> ViaNoop for &Probe<'_, T> {
+        fn validate_at(&self, _: &[PathSegment], _: &mut ValidationErrors) {}
+    }
+
+    pub fn join(path: &[PathSegment], segment: impl Into<PathSegment>) -> Vec<PathSegment> {
+        super::join(path, segment)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Email(String);
+
+    impl Validate for Email {
+        fn validate_at(&self, path: &[PathSegment], errors: &mut ValidationErrors) {
+            check(&self.0, &Refinement::Email, path, errors)
+        }
+    }
+
+    #[test]
+    fn string_checks() {
+        assert_eq!("abc".check(&Refinement::MinLength(3)), None);
+        assert!("ab".check(&Refinement::MinLength(3)).is_some());
+        assert!("abcd".check(&Refinement::MaxLength(3)).is_some());
+        assert!("".check(&Refinement::NonEmpty).is_some());
+        assert_eq!(
+            "abc".check(&Refinement::Regex(String::from("^[a-z]+$"))),
+            None
+        );
+        assert!("ABC"
+            .check(&Refinement::Regex(String::from("^[a-z]+$")))
+            .is_some());
+        assert_eq!("a@b.io".check(&Refinement::Email), None);
+        assert!("a@b".check(&Refinement::Email).is_some());
+        assert_eq!("https://example.com".check(&Refinement::Url), None);
+        assert!("example.com".check(&Refinement::Url).is_some());
+    }
+
+    /// the edge cases in which zod differs from a naive `local@domain.tld` check
+    #[test]
+    fn email_like_zod() {
+        for valid in [
+            "first.last@example.com",
+            "o'brien+tag@sub.example.co.uk",
+            "UPPER@EXAMPLE.COM",
+            "a-b_c@x-y.io",
+        ] {
+            assert_eq!(valid.check(&Refinement::Email), None, "{valid}");
+        }
+
+        for invalid in [
+            ".first@example.com",
+            "first..last@example.com",
+            "first.@example.com",
+            "first@example..com",
+            "first@-example.com",
+            "first@example.c",
+            "first@example.123",
+            "first@[127.0.0.1]",
+            "first last@example.com",
+            "first@example.com.",
+        ] {
+            assert!(invalid.check(&Refinement::Email).is_some(), "{invalid}");
+        }
+    }
+
+    /// zod's `.url()` accepts everything the `URL` constructor accepts
+    #[test]
+    fn url_like_zod() {
+        for valid in [
+            "https://example.com",
+            "mailto:someone@example.com",
+            "http://localhost:8080/path?query#hash",
+            "file:///etc/hosts",
+            "custom:opaque",
+            " https://example.com ",
+        ] {
+            assert_eq!(valid.check(&Refinement::Url), None, "{valid}");
+        }
+
+        for invalid in [
+            "",
+            "example.com",
+            "//example.com",
+            "http://",
+            "https://exa mple.com",
+        ] {
+            assert!(invalid.check(&Refinement::Url).is_some(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn javascript_regex_classes() {
+        assert_eq!(js_regex(r"^\d+\.\w$"), r"^[0-9]+\.[0-9A-Za-z_]$");
+        assert_eq!("12".check(&Refinement::Regex(String::from(r"^\d+$"))), None);
+        assert!("\u{1a90}"
+            .check(&Refinement::Regex(String::from(r"^\d+$")))
+            .is_some());
+    }
+
+    #[test]
+    fn number_checks() {
+        assert_eq!(5u8.check(&Refinement::Min(Numeric::Signed(0))), None);
+        assert!((-1i32)
+            .check(&Refinement::Min(Numeric::Signed(0)))
+            .is_some());
+        assert!(1.5f64
+            .check(&Refinement::Max(Numeric::Float(1.0)))
+            .is_some());
+        assert_eq!(None::<u8>.check(&Refinement::Min(Numeric::Signed(1))), None);
+    }
+
+    #[test]
+    fn nested_paths() {
+        let mut errors = ValidationErrors::default();
+        let value = BTreeMap::from([(
+            "a",
+            vec![Email(String::from("x")), Email(String::from("x@y.io"))],
+        )]);
+        value.validate_at(&[PathSegment::from("field")], &mut errors);
+
+        assert_eq!(errors.to_string(), "field.a[0]: invalid email");
+    }
+}
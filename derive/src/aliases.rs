@@ -0,0 +1,57 @@
+use crate::{direction::Direction, get_zod};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_derive_internals::attr;
+
+/// The names a field or variant accepts besides its own with `#[serde(alias = "..")]`. Aliases
+/// only affect deserialization and are therefore ignored for outputs.
+pub fn aliases(direction: Direction, name: &attr::Name, all: Vec<String>) -> Vec<String> {
+    match direction {
+        Direction::Output => Vec::new(),
+        Direction::Input => all
+            .into_iter()
+            .filter(|alias| *alias != name.deserialize_name())
+            .collect(),
+    }
+}
+
+/// the method call to apply to a `ZodField`
+pub fn expand(aliases: &[String]) -> TokenStream {
+    if aliases.is_empty() {
+        TokenStream::new()
+    } else {
+        quote!(.with_aliases(&[#(#aliases),*]))
+    }
+}
+
+/// `z.literal(name)` or a `z.enum` which also accepts the aliases
+pub fn expand_name_schema(name: &str, aliases: &[String]) -> TokenStream {
+    let zod = get_zod();
+
+    if aliases.is_empty() {
+        quote!(#zod::ast::ZodSchema::literal(#name))
+    } else {
+        quote! {
+            #zod::ast::ZodSchema::Enum(vec![
+                #zod::ast::EnumMember::new(#name, #name),
+                #(#zod::ast::EnumMember::new(#aliases, #aliases)),*
+            ])
+        }
+    }
+}
+
+/// the literal type of a name and its aliases
+pub fn expand_name_type(name: &str, aliases: &[String]) -> TokenStream {
+    let zod = get_zod();
+
+    if aliases.is_empty() {
+        quote!(#zod::ast::TsType::literal(#name))
+    } else {
+        quote! {
+            #zod::ast::TsType::Union(vec![
+                #zod::ast::TsType::literal(#name),
+                #(#zod::ast::TsType::literal(#aliases)),*
+            ])
+        }
+    }
+}
@@ -1,38 +1,86 @@
 use darling::{
     ast::{Data, Fields},
+    util::{Flag, Override},
     FromDeriveInput, FromField, FromVariant,
 };
 use syn::{Attribute, Type};
 
+use crate::{option_mode::OptionMode, unknown_keys::UnknownKeys};
+
 #[derive(FromDeriveInput)]
 #[darling(
     attributes(zod),
-    forward_attrs(allow, doc, cfg),
-    supports(struct_named, struct_newtype, struct_tuple, enum_any)
+    forward_attrs(allow, doc, cfg, repr, non_exhaustive),
+    supports(struct_any, enum_any)
 )]
 pub struct Input {
     pub ident: syn::Ident,
-    pub data: Data<EnumVariant, StructField>,
+    pub generics: syn::Generics,
+    pub data: Data<EnumVariant, Field>,
     pub namespace: syn::Path,
+    pub option: Option<OptionMode>,
+    /// implement `zod::validation::Validate`
+    pub validate: Flag,
+    /// export enums with only unit variants as a typescript `enum`
+    pub ts_enum: Flag,
+    /// the enum is serialized as its `#[repr(..)]` discriminant, eg. with `serde_repr`
+    pub repr: Flag,
+    /// outputs of a `#[non_exhaustive]` enum accept unknown variants
+    pub open: Flag,
+    /// the policy for unknown keys of objects
+    pub unknown_keys: Option<UnknownKeys>,
+    /// the foreign type this definition mirrors, like `#[serde(remote = "..")]`
+    pub remote: Option<syn::Path>,
+    /// a nominal type for newtypes, named after the type unless a name is given
+    pub brand: Option<Override<String>>,
+    /// newtypes over a concrete key type implement `ZodMapKey`
+    pub map_key: Flag,
     pub attrs: Vec<Attribute>,
 }
 
 #[derive(FromVariant, Clone)]
+#[darling(attributes(zod), forward_attrs(doc, deprecated))]
 pub struct EnumVariant {
     pub ident: syn::Ident,
-    pub fields: Fields<EnumField>,
-}
-
-#[derive(FromField, Clone)]
-pub struct StructField {
-    pub ident: Option<syn::Ident>,
-    pub ty: Type,
+    pub fields: Fields<Field>,
+    pub discriminant: Option<syn::Expr>,
+    /// the policy for unknown keys of struct variants, overrides the one of the container
+    pub unknown_keys: Option<UnknownKeys>,
+    pub attrs: Vec<Attribute>,
 }
 
+/// a field of a struct or of an enum variant
 #[derive(FromField, Clone)]
-pub struct EnumField {
+#[darling(attributes(zod), forward_attrs(doc, deprecated))]
+pub struct Field {
     pub ident: Option<syn::Ident>,
     pub ty: Type,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub min: Option<syn::Lit>,
+    pub max: Option<syn::Lit>,
+    pub regex: Option<syn::LitStr>,
+    pub email: Flag,
+    pub url: Flag,
+    pub non_empty: Flag,
+    /// represent wide integers as `bigint`
+    pub bigint: Flag,
+    /// represent wide integers as decimal strings
+    pub as_string: Flag,
+    /// represent the field like another type
+    #[darling(rename = "as")]
+    pub as_type: Option<Type>,
+    /// represent the field like the mirror of a remote type
+    pub with: Option<Type>,
+    /// hand written zod schema of the field
+    pub schema: Option<String>,
+    /// hand written typescript type of the field
+    pub ts: Option<String>,
+    /// a function returning the schema of the field
+    pub schema_with: Option<syn::Path>,
+    /// fill in the serde default of a missing input field with `.default(..)`
+    pub default: Flag,
+    pub attrs: Vec<Attribute>,
 }
 
 #[derive(FromDeriveInput)]
@@ -40,7 +88,7 @@ pub struct EnumField {
 pub struct NamespaceInput {
     pub vis: syn::Visibility,
     pub ident: syn::Ident,
-    pub data: Data<EnumVariant, StructField>,
+    pub data: Data<EnumVariant, Field>,
     pub name: Option<String>,
     pub attrs: Vec<Attribute>,
     pub generics: syn::Generics,
@@ -0,0 +1,113 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_derive_internals::{attr, Derive};
+
+use crate::get_zod;
+
+/// Serde may treat serialization and deserialization of a type differently. Every type deriving
+/// `Zod` therefore has an output flavour (`Foo`) describing the values rust serializes and an input
+/// flavour (`FooInput`) describing the values rust deserializes. The input flavour is only emitted
+/// if it differs from the output flavour.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Output,
+    Input,
+}
+
+impl Direction {
+    pub fn derive(self) -> Derive {
+        match self {
+            Self::Output => Derive::Serialize,
+            Self::Input => Derive::Deserialize,
+        }
+    }
+
+    pub fn name(self, name: &attr::Name) -> String {
+        match self {
+            Self::Output => name.serialize_name(),
+            Self::Input => name.deserialize_name(),
+        }
+    }
+
+    /// the name of the namespace member, mirrors of remote types are named like the remote type
+    pub fn member_name(self, attrs: &attr::Container, remote: Option<&syn::Path>) -> String {
+        let remote = remote
+            .or_else(|| attrs.remote())
+            .and_then(|path| path.segments.last())
+            .map(|segment| segment.ident.to_string());
+
+        match (self, remote) {
+            (Self::Output, Some(name)) => name,
+            (Self::Input, Some(name)) => format!("{name}Input"),
+            (Self::Output, None) => attrs.name().serialize_name(),
+            (Self::Input, None) => format!("{}Input", attrs.name().deserialize_name()),
+        }
+    }
+
+    /// the name references to this flavour of `instance` use, identical input flavours are not
+    /// emitted and refer to the output flavour instead
+    pub fn expand_ref_name(self, instance: &TokenStream, output: &str, input: &str) -> TokenStream {
+        let zod = get_zod();
+
+        match self {
+            Self::Output => quote!(#output),
+            Self::Input => quote! {
+                if #zod::input_differs::<#instance>() { #input } else { #output }
+            },
+        }
+    }
+
+    pub fn skip_field(self, attrs: &attr::Field) -> bool {
+        match self {
+            Self::Output => attrs.skip_serializing(),
+            Self::Input => attrs.skip_deserializing(),
+        }
+    }
+
+    pub fn skip_variant(self, attrs: &attr::Variant) -> bool {
+        match self {
+            Self::Output => attrs.skip_serializing(),
+            Self::Input => attrs.skip_deserializing(),
+        }
+    }
+
+    /// the type the container is converted to or from by serde
+    pub fn proxy_type(self, attrs: &attr::Container) -> Option<syn::Type> {
+        match self {
+            Self::Output => attrs.type_into().cloned(),
+            Self::Input => attrs.type_from().or_else(|| attrs.type_try_from()).cloned(),
+        }
+    }
+
+    /// `schema` / `input_schema`
+    pub fn schema(self) -> TokenStream {
+        match self {
+            Self::Output => quote!(schema),
+            Self::Input => quote!(input_schema),
+        }
+    }
+
+    /// `inline_schema` / `inline_input_schema`
+    pub fn inline_schema(self) -> TokenStream {
+        match self {
+            Self::Output => quote!(inline_schema),
+            Self::Input => quote!(inline_input_schema),
+        }
+    }
+
+    /// `type_def` / `input_type_def`
+    pub fn type_def(self) -> TokenStream {
+        match self {
+            Self::Output => quote!(type_def),
+            Self::Input => quote!(input_type_def),
+        }
+    }
+
+    /// `inline` / `inline_input`
+    pub fn inline(self) -> TokenStream {
+        match self {
+            Self::Output => quote!(inline),
+            Self::Input => quote!(inline_input),
+        }
+    }
+}
@@ -1,15 +1,21 @@
+use crate::get_zod;
 use darling::ToTokens;
 use proc_macro2::TokenStream;
+use proc_macro_error::abort;
 use quote::quote;
-use syn::{Lit, Meta};
+use syn::{Lit, Meta, NestedMeta};
 
+#[derive(Clone)]
 pub struct RustDocs {
     inner: Option<String>,
+    /// the note of `#[deprecated]`, empty if there is none
+    deprecated: Option<String>,
 }
 
 impl RustDocs {
     pub fn from_attrs(attrs: &[syn::Attribute]) -> Result<Self, syn::Error> {
         let mut full_docs = String::new();
+        let mut deprecated = None;
         for attr in attrs {
             match attr.parse_meta()? {
                 Meta::NameValue(nv) if nv.path.is_ident("doc") => {
@@ -22,23 +28,113 @@ impl RustDocs {
                         full_docs += doc_str;
                     }
                 }
+                Meta::Path(path) if path.is_ident("deprecated") => {
+                    deprecated = Some(String::new());
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("deprecated") => {
+                    if let Lit::Str(note) = nv.lit {
+                        deprecated = Some(note.value());
+                    }
+                }
+                Meta::List(list) if list.path.is_ident("deprecated") => {
+                    let note = list.nested.into_iter().find_map(|meta| match meta {
+                        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("note") => {
+                            match nv.lit {
+                                Lit::Str(note) => Some(note.value()),
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    });
+                    deprecated = Some(note.unwrap_or_default());
+                }
                 _ => {}
             }
         }
-        Ok(if full_docs.is_empty() {
-            Self { inner: None }
-        } else {
-            Self {
-                inner: Some(full_docs),
+        Ok(Self {
+            inner: (!full_docs.is_empty()).then_some(full_docs),
+            deprecated,
+        })
+    }
+
+    /// the item is annotated with `#[deprecated]`
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated.is_some()
+    }
+
+    /// the text of the JSDoc comment, the docs followed by a `@deprecated` tag
+    pub fn jsdoc(&self) -> Option<String> {
+        let deprecated = self.deprecated.as_ref().map(|note| {
+            if note.is_empty() {
+                String::from("@deprecated")
+            } else {
+                format!("@deprecated {note}")
             }
+        });
+
+        match (&self.inner, deprecated) {
+            (Some(docs), Some(deprecated)) => Some(format!("{docs}\n{deprecated}")),
+            (Some(docs), None) => Some(docs.clone()),
+            (None, deprecated) => deprecated,
+        }
+    }
+
+    /// the docs of a field or variant
+    pub fn of(attrs: &[syn::Attribute]) -> Self {
+        Self::from_attrs(attrs).unwrap_or_else(|err| abort!(err))
+    }
+
+    /// `.describe("...")`, the method call to apply to a `ZodSchema` or `ZodField`
+    pub fn expand_describe(&self) -> TokenStream {
+        match &self.inner {
+            Some(docs) => quote!(.describe(#docs)),
+            None => TokenStream::new(),
+        }
+    }
+
+    /// `.with_docs("...")`, the method call to apply to a `TsType`, `TsField` or `EnumMember`
+    pub fn expand_with_docs(&self) -> TokenStream {
+        match self.jsdoc() {
+            Some(docs) => quote!(.with_docs(#docs)),
+            None => TokenStream::new(),
+        }
+    }
+
+    /// wrap a schema which may still need a type annotation, like `object.into()`
+    pub fn expand_described_schema(&self, schema: TokenStream) -> TokenStream {
+        if self.inner.is_none() {
+            return schema;
+        }
+
+        let zod = get_zod();
+        let describe = self.expand_describe();
+        quote!({
+            let schema: #zod::ast::ZodSchema = #schema;
+            schema #describe
+        })
+    }
+
+    /// wrap a typescript type, see [RustDocs::expand_described_schema]
+    pub fn expand_documented_type(&self, ty: TokenStream) -> TokenStream {
+        if self.inner.is_none() && self.deprecated.is_none() {
+            return ty;
+        }
+
+        let zod = get_zod();
+        let with_docs = self.expand_with_docs();
+        quote!({
+            let ty: #zod::ast::TsType = #ty;
+            ty #with_docs
         })
     }
 }
 
 impl ToTokens for RustDocs {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        match self.inner {
-            Some(ref docs) => {
+        match self.jsdoc() {
+            Some(docs) => {
+                // `*/` would end the comment early
+                let docs = docs.replace("*/", "*\\/");
                 let docs = format!(
                     "/**\n{}*/\n",
                     docs.lines()
@@ -0,0 +1,215 @@
+use crate::{direction::Direction, get_zod};
+use proc_macro2::{TokenStream, TokenTree};
+use proc_macro_error::abort;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, punctuated::Punctuated, GenericParam, Generics, Ident, Lifetime, Token,
+    TypeParamBound, WherePredicate,
+};
+
+/// The generic parameters of a type deriving `Zod`.
+///
+/// Generic types are registered in their namespace by instantiating them with placeholder types
+/// which render as the names of the parameters. That way the generated typescript contains a
+/// generic interface and a zod factory function instead of a concrete instantiation. The bounds of
+/// the type are kept, placeholders implement the common std and serde traits to satisfy them. Any
+/// other trait bound can't be satisfied by a placeholder and is rejected.
+pub struct ZodGenerics {
+    generics: Generics,
+    params: Vec<Ident>,
+}
+
+impl ZodGenerics {
+    pub fn new(generics: &Generics) -> Self {
+        let zod = get_zod();
+        let mut generics = generics.clone();
+        let mut params = Vec::new();
+
+        for param in generics.params.iter_mut() {
+            match param {
+                GenericParam::Type(param) => {
+                    check_bounds(&param.bounds);
+                    param.bounds.push(parse_quote!(#zod::ZodType));
+                    params.push(param.ident.clone());
+                }
+                GenericParam::Const(param) => {
+                    abort!(param.ident.span(), "zod: const generics are not supported")
+                }
+                GenericParam::Lifetime(_) => {}
+            }
+        }
+
+        if let Some(where_clause) = &generics.where_clause {
+            for predicate in &where_clause.predicates {
+                if let WherePredicate::Type(predicate) = predicate {
+                    check_bounds(&predicate.bounds);
+                }
+            }
+        }
+
+        Self { generics, params }
+    }
+
+    /// generics of the `ZodType` impl, the bounds of the type and `ZodType` for all type parameters
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// whether the type refers to one of the type parameters
+    pub fn is_used_in(&self, ty: &syn::Type) -> bool {
+        fn contains(tokens: TokenStream, params: &[Ident]) -> bool {
+            tokens.into_iter().any(|tt| match tt {
+                TokenTree::Ident(ident) => params.contains(&ident),
+                TokenTree::Group(group) => contains(group.stream(), params),
+                _ => false,
+            })
+        }
+
+        contains(quote!(#ty), &self.params)
+    }
+
+    /// `vec![<T as ZodType>::inline_schema(), ...]`
+    pub fn expand_schema_args(&self, direction: Direction) -> TokenStream {
+        let zod = get_zod();
+        let params = &self.params;
+        let inline_schema = direction.inline_schema();
+        quote!(vec![#(<#params as #zod::ZodType>::#inline_schema()),*])
+    }
+
+    /// `vec![<T as ZodType>::inline().into(), ...]`
+    pub fn expand_type_args(&self, direction: Direction) -> TokenStream {
+        let zod = get_zod();
+        let params = &self.params;
+        let inline = direction.inline();
+        quote!(vec![#(<#params as #zod::ZodType>::#inline().into()),*])
+    }
+
+    /// `["T", "U"]`
+    pub fn names(&self) -> Vec<String> {
+        self.params.iter().map(|p| p.to_string()).collect()
+    }
+
+    /// define a placeholder type for every generic parameter
+    pub fn expand_placeholders(&self) -> TokenStream {
+        let zod = get_zod();
+        let serde = quote!(#zod::__private::serde);
+        let placeholders = self.params.iter().map(|param| {
+            let ident = placeholder_ident(param);
+            let name = param.to_string();
+
+            quote! {
+                #[allow(non_camel_case_types)]
+                #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+                struct #ident;
+
+                impl #zod::ZodType for #ident {
+                    fn schema() -> #zod::ast::ZodSchema {
+                        #zod::ast::ZodSchema::Raw(#zod::ast::schema_param(#name))
+                    }
+
+                    fn type_def() -> #zod::TsTypeDef {
+                        #zod::TsTypeDef::Type(#zod::ast::TsType::Raw(String::from(#name)))
+                    }
+                }
+
+                impl #zod::ZodMapKey for #ident {}
+
+                impl #zod::validation::Validate for #ident {
+                    fn validate_at(
+                        &self,
+                        _: &[#zod::validation::PathSegment],
+                        _: &mut #zod::validation::ValidationErrors,
+                    ) {
+                    }
+                }
+
+                impl #serde::Serialize for #ident {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: #serde::Serializer,
+                    {
+                        serializer.serialize_unit()
+                    }
+                }
+
+                impl<'de> #serde::Deserialize<'de> for #ident {
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: #serde::Deserializer<'de>,
+                    {
+                        <() as #serde::Deserialize>::deserialize(deserializer).map(|()| Self)
+                    }
+                }
+            }
+        });
+
+        quote!(#(#placeholders)*)
+    }
+
+    /// the type instantiated with its placeholders and `'static` lifetimes
+    pub fn expand_placeholder_instance(&self, ident: &Ident) -> TokenStream {
+        if self.generics.params.is_empty() {
+            return quote!(#ident);
+        }
+
+        let args = self.generics.params.iter().map(|param| match param {
+            GenericParam::Type(param) => {
+                let ident = placeholder_ident(&param.ident);
+                quote!(#ident)
+            }
+            GenericParam::Lifetime(_) => {
+                let lt = Lifetime::new("'static", proc_macro2::Span::call_site());
+                quote!(#lt)
+            }
+            GenericParam::Const(_) => unreachable!("rejected in ZodGenerics::new"),
+        });
+
+        quote!(#ident<#(#args),*>)
+    }
+}
+
+/// traits implemented by the placeholder types, auto traits and serde's `DeserializeOwned`
+const PLACEHOLDER_TRAITS: &[&str] = &[
+    "Clone",
+    "Copy",
+    "Debug",
+    "Default",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "Hash",
+    "Serialize",
+    "Deserialize",
+    "DeserializeOwned",
+    "ZodType",
+    "ZodMapKey",
+    "Validate",
+    "Send",
+    "Sync",
+    "Unpin",
+    "Sized",
+];
+
+fn check_bounds(bounds: &Punctuated<TypeParamBound, Token![+]>) {
+    for bound in bounds {
+        if let TypeParamBound::Trait(bound) = bound {
+            let segment = bound.path.segments.last().expect("paths are not empty");
+            if !PLACEHOLDER_TRAITS.contains(&segment.ident.to_string().as_str()) {
+                abort!(
+                    bound.path,
+                    "zod: `{}` bounds on type parameters are not supported, generic types are registered with placeholders which only implement the std derives, serde and zod traits",
+                    segment.ident
+                )
+            }
+        }
+    }
+}
+
+fn placeholder_ident(param: &Ident) -> Ident {
+    format_ident!("__ZodParam_{}", param)
+}
@@ -1,8 +1,17 @@
-use crate::{args::EnumField, get_zod};
+use crate::{
+    aliases,
+    args::Field,
+    direction::Direction,
+    docs::RustDocs,
+    get_zod, integer_mode,
+    option_mode::{FieldModifiers, OptionMode},
+    schema_override::SchemaOverride,
+    validation,
+};
 
 use darling::ast::Style;
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
+use quote::quote_spanned;
 use syn::spanned::Spanned;
 
 /// represents the fields inside a variant
@@ -14,6 +23,8 @@ impl<'a> VariantFields<'a> {
     pub fn new(
         variant: &'a crate::args::EnumVariant,
         serde_variant: &'a serde_derive_internals::ast::Variant,
+        option_mode: Option<OptionMode>,
+        direction: Direction,
     ) -> Self {
         let style = variant.fields.style;
 
@@ -22,16 +33,30 @@ impl<'a> VariantFields<'a> {
             .iter()
             .zip(&serde_variant.fields)
             .filter_map(|(enum_field, f)| {
-                if !f.attrs.skip_deserializing() {
-                    Some(match style {
-                        Style::Tuple => VariantField::Tuple(TupleField {
+                if !direction.skip_field(&f.attrs) {
+                    let integer_mode = integer_mode::expand(enum_field, &f.attrs);
+
+                    let value = FieldValue {
+                        enum_field,
+                        direction,
+                        modifiers: FieldModifiers::new(
+                            direction,
+                            option_mode,
                             enum_field,
-                            optional: !f.attrs.default().is_none(),
-                        }),
+                            &f.attrs,
+                        ),
+                        refinements: validation::expand_refinements(enum_field),
+                        integer_mode,
+                        schema_override: SchemaOverride::new(enum_field),
+                    };
+
+                    Some(match style {
+                        Style::Tuple => VariantField::Tuple(value),
                         Style::Struct => VariantField::Named(NamedField {
-                            enum_field,
-                            name: f.attrs.name().deserialize_name(),
-                            optional: !f.attrs.default().is_none(),
+                            name: direction.name(f.attrs.name()),
+                            aliases: aliases::aliases(direction, f.attrs.name(), f.attrs.aliases()),
+                            docs: RustDocs::of(&enum_field.attrs),
+                            value,
                         }),
                         Style::Unit => unreachable!(),
                     })
@@ -58,7 +83,7 @@ impl<'a> VariantFields<'a> {
 
 enum VariantField<'a> {
     Named(NamedField<'a>),
-    Tuple(TupleField<'a>),
+    Tuple(FieldValue<'a>),
 }
 
 impl<'a> VariantField<'a> {
@@ -77,66 +102,83 @@ impl<'a> VariantField<'a> {
 }
 
 struct NamedField<'a> {
-    enum_field: &'a EnumField,
     name: String,
-    optional: bool,
+    aliases: Vec<String>,
+    docs: RustDocs,
+    value: FieldValue<'a>,
 }
 
-struct TupleField<'a> {
-    enum_field: &'a EnumField,
-    optional: bool,
+/// the value of a named or unnamed field
+struct FieldValue<'a> {
+    enum_field: &'a Field,
+    direction: Direction,
+    modifiers: FieldModifiers,
+    refinements: TokenStream,
+    /// `.with_integer_mode(...)`, see [integer_mode::expand]
+    integer_mode: Option<TokenStream>,
+    /// replaces the schema of the field type
+    schema_override: Option<SchemaOverride<'a>>,
 }
 
 impl<'a> NamedField<'a> {
     fn expand_type_def(&'a self) -> TokenStream {
         let zod = get_zod();
-        let ty = &self.enum_field.ty;
-        let span = self.enum_field.ty.span();
+        let span = self.value.enum_field.ty.span();
         let name = &self.name;
+        let value = self.value.expand_value_type();
+        let modifiers = self.value.modifiers.expand();
+        let with_docs = self.docs.expand_with_docs();
 
-        if self.optional {
-            quote_spanned!(span => format!("{}?: {} | undefined", #name, <#ty as #zod::ZodType>::inline()))
-        } else {
-            quote_spanned!(span => format!("{}: {}", #name, <#ty as #zod::ZodType>::inline()))
-        }
+        quote_spanned!(span => #zod::ast::TsField::new(#name, #value) #modifiers #with_docs)
     }
 
     fn expand_schema(&self) -> TokenStream {
         let zod = get_zod();
         let name = &self.name;
-        let ty = &self.enum_field.ty;
-        let maybe_optional = if self.optional {
-            quote!(".optional()")
-        } else {
-            quote!("")
-        };
+        let span = self.value.enum_field.ty.span();
+        let schema = self.value.expand_schema();
+        let aliases = aliases::expand(&self.aliases);
+        let describe = self.docs.expand_describe();
 
-        quote_spanned!(ty.span() => format!("{}: {}{}", #name, <#ty as #zod::ZodType>::inline_schema(), #maybe_optional))
+        quote_spanned!(span => #zod::ast::ZodField::new(#name, #schema) #aliases #describe)
     }
 }
 
-impl<'a> TupleField<'a> {
-    fn expand_type_def(&'a self) -> TokenStream {
+impl<'a> FieldValue<'a> {
+    fn expand_value_type(&self) -> TokenStream {
         let zod = get_zod();
         let ty = &self.enum_field.ty;
-        let span = self.enum_field.ty.span();
+        let inline = self.direction.inline();
+        let integer_mode = &self.integer_mode;
 
-        if self.optional {
-            quote_spanned!(span => format!("{} | undefined", <#ty as #zod::ZodType>::inline()))
+        if let Some(schema_override) = &self.schema_override {
+            let value = schema_override.expand_type(self.direction);
+            quote_spanned!(ty.span() => #value #integer_mode)
         } else {
-            quote_spanned!(span => format!("{}", <#ty as #zod::ZodType>::inline()))
+            quote_spanned!(ty.span() => #zod::ast::TsType::from(<#ty as #zod::ZodType>::#inline()) #integer_mode)
         }
     }
 
+    fn expand_type_def(&self) -> TokenStream {
+        let value = self.expand_value_type();
+        let modifiers = self.modifiers.expand();
+
+        quote_spanned!(self.enum_field.ty.span() => #value #modifiers)
+    }
+
     fn expand_schema(&self) -> TokenStream {
         let zod = get_zod();
         let ty = &self.enum_field.ty;
-        let maybe_optional = if self.optional {
-            quote!(".optional()")
+        let inline_schema = self.direction.inline_schema();
+        let refinements = &self.refinements;
+        let integer_mode = &self.integer_mode;
+        let modifiers = self.modifiers.expand_schema();
+
+        if let Some(schema_override) = &self.schema_override {
+            let schema = schema_override.expand_schema(self.direction);
+            quote_spanned!(ty.span() => #schema #integer_mode #refinements #modifiers)
         } else {
-            quote!("")
-        };
-
-        quote_spanned!(ty.span() => format!("{}{}", <#ty as #zod::ZodType>::inline_schema(), #maybe_optional))
+            quote_spanned!(ty.span() => <#ty as #zod::ZodType>::#inline_schema() #integer_mode #refinements #modifiers)
+        }
     }
 }
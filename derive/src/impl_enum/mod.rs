@@ -1,7 +1,11 @@
 mod field;
+mod repr;
 mod variant;
 
-use crate::{docs::RustDocs, expand_type_registration, impl_inventory};
+use crate::{
+    direction::Direction, docs::RustDocs, expand_type_registration, generics::ZodGenerics,
+    impl_inventory,
+};
 use variant::Variant;
 
 use super::args;
@@ -12,164 +16,414 @@ use serde_derive_internals::{
     ast::{self, Data},
     attr::TagType,
 };
+use syn::Path;
 
 pub fn expand(
     input: args::Input,
     variants: &[args::EnumVariant],
-    serde_ast: ast::Container,
+    serde_output: ast::Container,
+    serde_input: ast::Container,
     docs: RustDocs,
 ) -> TokenStream {
-    let variant_ast = match serde_ast.data {
-        Data::Enum(ref variants) => variants,
-        Data::Struct(_, _) => unreachable!(),
-    };
-
-    let name = serde_ast.attrs.name().deserialize_name();
-    let tag = serde_ast.attrs.tag();
-
-    let variants = variants
-        .iter()
-        .zip(variant_ast.iter())
-        .filter(|(_, ast)| !ast.attrs.skip_deserializing())
-        .map(|(v, ast)| Variant::new(v, &serde_ast, ast))
-        .collect();
-
-    let from_ty = serde_ast
-        .attrs
-        .type_from()
-        .or_else(|| serde_ast.attrs.type_try_from())
-        .cloned();
+    if input.brand.is_some() {
+        abort!(input.ident.span(), "zod: `brand` requires a newtype struct")
+    }
+
+    let repr = repr::members(&input, variants);
+    let output = Shape::new(
+        Direction::Output,
+        &input,
+        variants,
+        &serde_output,
+        repr.clone(),
+    );
+    let mut input_shape = Shape::new(Direction::Input, &input, variants, &serde_input, repr);
+
+    // an input with the same values is identical to the output and not emitted, it refers to the
+    // values of the output instead
+    if input_shape.unit_values() == output.unit_values() {
+        input_shape.enum_name = output.enum_name.clone();
+    }
 
     Enum {
         input,
-        variants,
-        name,
-        tag,
         docs,
-        from_ty,
+        output,
+        input_shape,
     }
     .expand()
 }
 
 struct Enum<'a> {
     input: args::Input,
-    variants: Vec<Variant<'a>>,
-    name: String,
-    tag: &'a TagType,
     docs: RustDocs,
-    from_ty: Option<syn::Type>,
+    output: Shape<'a>,
+    input_shape: Shape<'a>,
 }
 
 impl<'a> Enum<'a> {
     pub fn expand(&self) -> TokenStream {
         let ident = &self.input.ident;
         let zod = crate::get_zod();
-        let name = &self.name;
         let ns_path = &self.input.namespace;
         let docs = &self.docs;
-        let schema = self.expand_schema();
-        let type_def = self.expand_typ_defs();
+        let generics = ZodGenerics::new(&self.input.generics);
+
+        let instance = generics.expand_placeholder_instance(ident);
+        let placeholders = generics.expand_placeholders();
+        let output = self
+            .output
+            .expand(ns_path, &generics, &instance, &self.output.name);
+        let input = self
+            .input_shape
+            .expand(ns_path, &generics, &instance, &self.output.name);
+
         let type_register = expand_type_registration(ident, ns_path);
-        let inventory = impl_inventory::expand(ident, ns_path, name);
+        let inventory = impl_inventory::expand(
+            ident,
+            ns_path,
+            &self.output.name,
+            &self.input_shape.name,
+            &generics,
+        );
+        let (impl_generics, ty_generics, where_clause) = generics.generics().split_for_impl();
+        let map_key = self.expand_map_key(&generics);
 
-        if let Some(t) = &self.from_ty {
-            quote! {
-                impl #zod::ZodType for #ident {
-                    fn schema() -> String {
-                        <#t as #zod::ZodType>::schema()
-                    }
+        quote! {
+            #placeholders
 
-                    fn inline_schema() -> String {
-                        <#t as #zod::ZodType>::inline_schema()
-                    }
+            impl #impl_generics #zod::ZodType for #ident #ty_generics #where_clause {
+                #output
 
-                    fn type_def() -> #zod::TsTypeDef {
-                        <#t as #zod::ZodType>::type_def()
-                    }
+                #input
 
-                    fn inline() -> #zod::InlinedType {
-                        <#t as #zod::ZodType>::inline()
-                    }
+                fn docs() -> Option<&'static str> {
+                    Some(#docs)
+                }
+            }
 
-                    fn docs() -> Option<&'static str> {
-                        Some(#docs)
-                    }
+            #map_key
+
+            #inventory
+
+            #type_register
+        }
+    }
+
+    /// externally tagged enums with only unit variants serialize to strings and can be used as
+    /// map keys
+    fn expand_map_key(&self, generics: &ZodGenerics) -> TokenStream {
+        let shape = &self.output;
+
+        if shape.proxy_ty.is_some() || shape.unit_values().is_none() {
+            return TokenStream::new();
+        }
+
+        let zod = crate::get_zod();
+        let ident = &self.input.ident;
+        let (impl_generics, ty_generics, where_clause) = generics.generics().split_for_impl();
+        let finite = !shape.fallback;
+
+        quote! {
+            impl #impl_generics #zod::ZodMapKey for #ident #ty_generics #where_clause {
+                fn finite() -> bool {
+                    #finite
                 }
+            }
+        }
+    }
+}
+
+/// The shape of an enum in one [Direction]
+struct Shape<'a> {
+    direction: Direction,
+    variants: Vec<Variant<'a>>,
+    name: String,
+    tag: &'a TagType,
+    proxy_ty: Option<syn::Type>,
+    ts_enum: bool,
+    /// the names and discriminants of a `#[zod(repr)]` enum
+    repr: Option<Vec<(String, i128)>>,
+    /// accept unknown variants, see [Shape::expand_fallbacks]
+    fallback: bool,
+    /// the name of the typescript enum or of the const object holding the values
+    enum_name: String,
+}
 
-                #inventory
+impl<'a> Shape<'a> {
+    fn new(
+        direction: Direction,
+        input: &args::Input,
+        variants: &'a [args::EnumVariant],
+        serde_ast: &'a ast::Container,
+        repr: Option<Vec<(String, i128)>>,
+    ) -> Self {
+        let variant_ast = match serde_ast.data {
+            Data::Enum(ref variants) => variants,
+            Data::Struct(_, _) => unreachable!(),
+        };
 
-                #type_register
+        let variants = variants
+            .iter()
+            .zip(variant_ast.iter())
+            .filter(|(_, ast)| !direction.skip_variant(&ast.attrs))
+            .map(|(v, ast)| Variant::new(v, serde_ast, ast, input, direction))
+            .collect();
 
+        let open = input.open.is_present();
+
+        if open {
+            let non_exhaustive = input
+                .attrs
+                .iter()
+                .any(|attr| attr.path.is_ident("non_exhaustive"));
+
+            if !non_exhaustive {
+                abort!(
+                    input.ident.span(),
+                    "zod: `open` requires a `#[non_exhaustive]` enum"
+                )
             }
-        } else {
+
+            if let TagType::None = serde_ast.attrs.tag() {
+                abort!(input.ident.span(), "zod: `open` requires a tagged enum")
+            }
+        }
+
+        let fallback = match direction {
+            Direction::Output => open,
+            Direction::Input => variant_ast.iter().any(|variant| variant.attrs.other()),
+        };
+
+        let name = direction.member_name(&serde_ast.attrs, input.remote.as_ref());
+
+        let shape = Self {
+            direction,
+            variants,
+            enum_name: format!("{name}Enum"),
+            name,
+            tag: serde_ast.attrs.tag(),
+            proxy_ty: direction.proxy_type(&serde_ast.attrs),
+            ts_enum: input.ts_enum.is_present(),
+            repr,
+            fallback,
+        };
+
+        if shape.ts_enum && shape.unit_values().is_none() && shape.repr.is_none() {
+            abort!(
+                input.ident.span(),
+                "zod: `ts_enum` requires an externally tagged enum with only unit variants"
+            )
+        }
+
+        shape
+    }
+
+    /// the string values of an externally tagged enum with only unit variants
+    fn unit_values(&self) -> Option<Vec<&str>> {
+        if !matches!(self.tag, TagType::External) || self.variants.is_empty() || self.repr.is_some()
+        {
+            return None;
+        }
+
+        self.variants
+            .iter()
+            .map(|variant| match variant {
+                Variant::Unit(unit) => Some(unit.name.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// the enum has no variants which can be (de)serialized
+    fn is_empty(&self) -> bool {
+        match &self.repr {
+            Some(repr) => repr.is_empty(),
+            None => self.variants.is_empty(),
+        }
+    }
+
+    /// the `ZodType` methods of this direction, `instance` is the registered instance of the type
+    fn expand(
+        &self,
+        ns_path: &Path,
+        generics: &ZodGenerics,
+        instance: &TokenStream,
+        output_name: &str,
+    ) -> TokenStream {
+        let zod = crate::get_zod();
+        let name = self
+            .direction
+            .expand_ref_name(instance, output_name, &self.name);
+
+        let schema_fn = self.direction.schema();
+        let inline_schema_fn = self.direction.inline_schema();
+        let type_def_fn = self.direction.type_def();
+        let inline_fn = self.direction.inline();
+
+        if let Some(t) = &self.proxy_ty {
             quote! {
-                impl #zod::ZodType for #ident {
-                    fn schema() -> String {
-                        #schema
-                    }
+                fn #schema_fn() -> #zod::ast::ZodSchema {
+                    <#t as #zod::ZodType>::#schema_fn()
+                }
 
-                    fn inline_schema() -> String {
-                        format!("z.lazy(() => {}.{})",
-                            <#ns_path as #zod::Namespace>::NAME,
-                            #name
-                        )
-                    }
+                fn #inline_schema_fn() -> #zod::ast::ZodSchema {
+                    <#t as #zod::ZodType>::#inline_schema_fn()
+                }
 
-                    fn type_def() -> #zod::TsTypeDef {
-                        #zod::TsTypeDef::Type({ #type_def })
-                    }
+                fn #type_def_fn() -> #zod::TsTypeDef {
+                    <#t as #zod::ZodType>::#type_def_fn()
+                }
 
-                    fn inline() -> #zod::InlinedType {
-                        #zod::InlinedType::Ref {
-                            ns_name: <#ns_path as #zod::Namespace>::NAME,
-                            name: #name
-                        }
+                fn #inline_fn() -> #zod::InlinedType {
+                    <#t as #zod::ZodType>::#inline_fn()
+                }
+            }
+        } else {
+            let schema = self.expand_schema(ns_path);
+            let type_def = self.expand_typ_defs(ns_path);
+            let schema_args = generics.expand_schema_args(self.direction);
+            let type_args = generics.expand_type_args(self.direction);
+
+            quote! {
+                fn #schema_fn() -> #zod::ast::ZodSchema {
+                    #schema
+                }
+
+                fn #inline_schema_fn() -> #zod::ast::ZodSchema {
+                    #zod::ast::ZodSchema::Lazy {
+                        ns_name: <#ns_path as #zod::Namespace>::NAME,
+                        name: #name,
+                        args: #schema_args
                     }
+                }
 
-                    fn docs() -> Option<&'static str> {
-                        Some(#docs)
+                fn #type_def_fn() -> #zod::TsTypeDef {
+                    #zod::TsTypeDef::Type(#type_def)
+                }
+
+                fn #inline_fn() -> #zod::InlinedType {
+                    #zod::InlinedType::Ref {
+                        ns_name: <#ns_path as #zod::Namespace>::NAME,
+                        name: #name,
+                        args: #type_args
                     }
                 }
+            }
+        }
+    }
+
+    /// The schemas and types of the variants which are accepted besides the known ones. Outputs
+    /// of `open` enums accept unknown variants so older clients keep working when variants are
+    /// added. Inputs of enums with a `#[serde(other)]` variant accept any tag.
+    fn expand_fallbacks(&self) -> Vec<(TokenStream, TokenStream)> {
+        let zod = crate::get_zod();
+
+        if !self.fallback {
+            return Vec::new();
+        }
+
+        match self.tag {
+            TagType::External if self.repr.is_some() => vec![(
+                quote!(#zod::ast::ZodSchema::Number(vec![#zod::ast::NumberCheck::Int])),
+                quote!(#zod::ast::TsType::Number.open()),
+            )],
+            TagType::External => {
+                let unit = self.variants.iter().any(|v| matches!(v, Variant::Unit(_)));
+                let data = self.variants.iter().any(|v| !matches!(v, Variant::Unit(_)));
 
-                #inventory
+                let string = (
+                    quote!(#zod::ast::ZodSchema::String(Vec::new())),
+                    quote!(#zod::ast::TsType::String.open()),
+                );
 
-                #type_register
+                let record = (
+                    quote! {
+                        #zod::ast::ZodSchema::Record(
+                            Box::new(#zod::ast::ZodSchema::String(Vec::new())),
+                            Box::new(#zod::ast::ZodSchema::Unknown)
+                        )
+                    },
+                    quote! {
+                        #zod::ast::TsType::Record(
+                            Box::new(#zod::ast::TsType::String),
+                            Box::new(#zod::ast::TsType::Unknown)
+                        )
+                    },
+                );
 
+                [(unit, string), (data, record)]
+                    .into_iter()
+                    .filter_map(|(present, fallback)| present.then_some(fallback))
+                    .collect()
             }
+            TagType::Internal { tag } | TagType::Adjacent { tag, .. } => vec![(
+                quote! {
+                    #zod::ast::ZodSchema::object(vec![
+                        #zod::ast::ZodField::new(#tag, #zod::ast::ZodSchema::String(Vec::new()))
+                    ])
+                },
+                quote! {
+                    #zod::ast::TsType::Object(vec![
+                        #zod::ast::TsField::new(#tag, #zod::ast::TsType::String.open())
+                    ])
+                },
+            )],
+            TagType::None => unreachable!("untagged enums can't be open"),
         }
     }
 
-    fn abort_empty(&self) -> ! {
-        abort!(
-            self.input.ident.span(),
-            "deriving zod on empty enums is not supported."
-        )
+    fn expand_schema(&self, ns_path: &Path) -> TokenStream {
+        let schema = self.expand_known_schema(ns_path);
+        let fallbacks = self
+            .expand_fallbacks()
+            .into_iter()
+            .map(|(schema, _)| schema);
+
+        quote!(#schema #(.or(#fallbacks))*)
     }
 
-    fn expand_schema(&self) -> TokenStream {
+    fn expand_known_schema(&self, ns_path: &Path) -> TokenStream {
+        let zod = crate::get_zod();
+
+        // empty enums are uninhabited
+        if self.is_empty() {
+            return quote!(#zod::ast::ZodSchema::Never);
+        }
+
+        if let Some(members) = self.expand_enum_members() {
+            return if self.ts_enum {
+                let name = &self.enum_name;
+                quote! {
+                    #zod::ast::ZodSchema::NativeEnum {
+                        ns_name: <#ns_path as #zod::Namespace>::NAME,
+                        name: #name,
+                        members: vec![#(#members),*]
+                    }
+                }
+            } else {
+                quote!(#zod::ast::ZodSchema::Enum(vec![#(#members),*]))
+            };
+        }
+
         match self.variants.len() {
-            0 => self.abort_empty(),
+            0 => unreachable!("empty enums are never"),
             1 => self.variants.first().expect("one variant").expand_schema(),
             _ => {
                 let expanded_variant_schemas = self.variants.iter().map(|v| v.expand_schema());
 
                 match self.tag {
-                    TagType::External => {
+                    TagType::External | TagType::None => {
                         quote! {
-                            let variants: ::std::vec::Vec<String> = vec![#(#expanded_variant_schemas),*];
-                            format!("z.union([{}])", variants.join(", "))
+                            #zod::ast::ZodSchema::Union(vec![#(#expanded_variant_schemas),*])
                         }
                     }
                     TagType::Internal { tag } | TagType::Adjacent { tag, .. } => {
                         quote! {
-                            let variants: ::std::vec::Vec<String> = vec![#(#expanded_variant_schemas),*];
-                            format!("z.discriminatedUnion(\"{}\", [{}])", #tag, variants.join(", "))
-                        }
-                    }
-                    TagType::None => {
-                        quote! {
-                            let variants: ::std::vec::Vec<String> = vec![#(#expanded_variant_schemas),*];
-                            format!("z.union([{}])", variants.join(", "))
+                            #zod::ast::ZodSchema::DiscriminatedUnion {
+                                tag: String::from(#tag),
+                                variants: vec![#(#expanded_variant_schemas),*]
+                            }
                         }
                     }
                 }
@@ -177,9 +431,78 @@ impl<'a> Enum<'a> {
         }
     }
 
-    fn expand_typ_defs(&self) -> TokenStream {
+    /// the `EnumMember`s of an enum with only unit variants
+    fn expand_enum_members(&self) -> Option<Vec<TokenStream>> {
+        let zod = crate::get_zod();
+
+        if let Some(repr) = &self.repr {
+            return Some(
+                repr.iter()
+                    .map(|(name, value)| quote!(#zod::ast::EnumMember::new(#name, #value)))
+                    .collect(),
+            );
+        }
+
+        self.unit_values()?;
+
+        // input enums also accept the aliases of their variants
+        let members = self
+            .variants
+            .iter()
+            .filter_map(|variant| match variant {
+                Variant::Unit(unit) => Some(unit),
+                _ => None,
+            })
+            .flat_map(|unit| {
+                let zod = &zod;
+                let with_docs = unit.docs.expand_with_docs();
+                std::iter::once(&unit.name)
+                    .chain(&unit.aliases)
+                    .map(move |value| quote!(#zod::ast::EnumMember::new(#value, #value) #with_docs))
+            })
+            .collect();
+
+        Some(members)
+    }
+
+    fn expand_typ_defs(&self, ns_path: &Path) -> TokenStream {
+        let type_def = self.expand_known_typ_defs(ns_path);
+        let fallbacks = self.expand_fallbacks().into_iter().map(|(_, ty)| ty);
+
+        quote!(#type_def #(.or(#fallbacks))*)
+    }
+
+    fn expand_known_typ_defs(&self, ns_path: &Path) -> TokenStream {
+        let zod = crate::get_zod();
+
+        if self.is_empty() {
+            return quote!(#zod::ast::TsType::Never);
+        }
+
+        if self.ts_enum {
+            let name = &self.enum_name;
+            return quote! {
+                #zod::ast::TsType::Ref {
+                    ns_name: <#ns_path as #zod::Namespace>::NAME,
+                    name: #name,
+                    args: Vec::new()
+                }
+            };
+        }
+
+        if let Some(repr) = &self.repr {
+            let values = repr.iter().map(|(_, value)| value);
+            return match repr.len() {
+                0 => unreachable!("empty enums are never"),
+                1 => quote!(#zod::ast::TsType::literal(#(#values)*)),
+                _ => {
+                    quote!(#zod::ast::TsType::Union(vec![#(#zod::ast::TsType::literal(#values)),*]))
+                }
+            };
+        }
+
         match self.variants.len() {
-            0 => self.abort_empty(),
+            0 => unreachable!("empty enums are never"),
             1 => self
                 .variants
                 .first()
@@ -189,8 +512,7 @@ impl<'a> Enum<'a> {
                 let expanded_variant_type_defs = self.variants.iter().map(|v| v.expand_type_def());
 
                 quote! {
-                    let type_defs: std::vec::Vec<String> = vec![#(#expanded_variant_type_defs),*];
-                    type_defs.join(" | ")
+                    #zod::ast::TsType::Union(vec![#(#expanded_variant_type_defs),*])
                 }
             }
         }
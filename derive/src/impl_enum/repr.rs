@@ -0,0 +1,87 @@
+use crate::args;
+use proc_macro_error::abort;
+use syn::{spanned::Spanned, Expr, Lit, Meta, NestedMeta, UnOp};
+
+const INTEGER_REPRS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// `#[zod(repr)]` enums are serialized as their discriminant, eg. with `serde_repr`. Returns the
+/// name and the discriminant of every variant.
+pub fn members(input: &args::Input, variants: &[args::EnumVariant]) -> Option<Vec<(String, i128)>> {
+    if !input.repr.is_present() {
+        return None;
+    }
+
+    if !input.attrs.iter().any(is_integer_repr) {
+        abort!(
+            input.ident.span(),
+            "zod: `repr` requires an integer representation, eg. `#[repr(u8)]`"
+        )
+    }
+
+    let mut next = 0;
+
+    let members = variants
+        .iter()
+        .map(|variant| {
+            if !variant.fields.is_empty() {
+                abort!(
+                    variant.ident.span(),
+                    "zod: `repr` requires an enum with only unit variants"
+                )
+            }
+
+            let value = match &variant.discriminant {
+                Some(expr) => discriminant(expr),
+                None => next,
+            };
+
+            next = value + 1;
+            (variant.ident.to_string(), value)
+        })
+        .collect();
+
+    Some(members)
+}
+
+fn is_integer_repr(attr: &syn::Attribute) -> bool {
+    if !attr.path.is_ident("repr") {
+        return false;
+    }
+
+    match attr.parse_meta() {
+        Ok(Meta::List(list)) => list.nested.iter().any(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) => INTEGER_REPRS.iter().any(|r| path.is_ident(r)),
+            _ => false,
+        }),
+        _ => false,
+    }
+}
+
+/// `1` or `-1`
+fn discriminant(expr: &Expr) -> i128 {
+    let parsed = match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse::<i128>().ok(),
+        Expr::Unary(syn::ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match expr.as_ref() {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Int(int), ..
+            }) => int.base10_parse::<i128>().ok().map(|n| -n),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    parsed.unwrap_or_else(|| {
+        abort!(
+            expr.span(),
+            "zod: the discriminants of `repr` enums must be integer literals"
+        )
+    })
+}
@@ -8,6 +8,7 @@ use unit_variant::UnitVariant;
 
 use super::args;
 use super::field;
+use crate::{aliases, direction::Direction, docs::RustDocs, unknown_keys::UnknownKeys};
 use darling::ast::Style;
 use proc_macro2::TokenStream;
 use serde_derive_internals::ast;
@@ -24,45 +25,81 @@ impl<'a> Variant<'a> {
         variant: &'a args::EnumVariant,
         serde_ast: &'a ast::Container,
         serde_variant: &'a serde_derive_internals::ast::Variant,
+        input: &args::Input,
+        direction: Direction,
     ) -> Self {
         let ident = &variant.ident;
-        let fields = field::VariantFields::new(variant, serde_variant);
-        let name = serde_variant.attrs.name().deserialize_name();
+        let fields = field::VariantFields::new(variant, serde_variant, input.option, direction);
+        let name = direction.name(serde_variant.attrs.name());
+        let aliases = aliases::aliases(
+            direction,
+            serde_variant.attrs.name(),
+            serde_variant.attrs.aliases(),
+        );
+        let docs = RustDocs::of(&variant.attrs);
         let tag = serde_ast.attrs.tag();
         let span = ident.span();
 
         match variant.fields.style {
-            Style::Unit => Self::Unit(UnitVariant { span, tag, name }),
+            Style::Unit => Self::Unit(UnitVariant {
+                span,
+                tag,
+                name,
+                aliases,
+                docs,
+            }),
             Style::Tuple => Self::Tuple(TupleVariant {
                 span,
                 tag,
                 name,
+                aliases,
+                docs,
                 fields,
+                is_empty: variant.fields.is_empty(),
             }),
             Style::Struct => Self::Struct(StructVariant {
                 ident,
+                name,
+                aliases,
+                docs,
                 fields,
                 serde_ast,
-                attrs: &serde_variant.attrs,
+                unknown_keys: UnknownKeys::resolve(
+                    variant.unknown_keys.or(input.unknown_keys),
+                    &serde_ast.attrs,
+                ),
             }),
         }
     }
 
+    /// the doc comment of the variant
+    pub fn docs(&self) -> &RustDocs {
+        match self {
+            Variant::Unit(unit) => &unit.docs,
+            Variant::Struct(strukt) => &strukt.docs,
+            Variant::Tuple(tuple) => &tuple.docs,
+        }
+    }
+
     /// expand a single variant of an enum into a zod schema
     pub fn expand_schema(&self) -> TokenStream {
-        match self {
+        let schema = match self {
             Variant::Unit(unit) => unit.expand_schema(),
             Variant::Struct(strukt) => strukt.expand_schema(),
             Variant::Tuple(tuple) => tuple.expand_schema(),
-        }
+        };
+
+        self.docs().expand_described_schema(schema)
     }
 
     /// expand a single enum variant to TS definition
     pub fn expand_type_def(&self) -> TokenStream {
-        match self {
+        let ty = match self {
             Variant::Unit(unit) => unit.expand_type_defs(),
             Variant::Struct(strukt) => strukt.expand_type_defs(),
             Variant::Tuple(tuple) => tuple.expand_type_defs(),
-        }
+        };
+
+        self.docs().expand_documented_type(ty)
     }
 }
@@ -1,96 +1,106 @@
 use super::field;
+use crate::{aliases, docs::RustDocs, get_zod, unknown_keys::UnknownKeys};
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
+use quote::quote_spanned;
 use serde_derive_internals::{ast, attr::TagType};
 use syn::Ident;
 
-/// represents a struct variant of an enum, it has one or more named fields. It is represeneted as
+/// represents a struct variant of an enum, it has zero or more named fields. It is represeneted as
 /// an object in typescript.
 pub struct StructVariant<'a> {
     pub ident: &'a Ident,
+    pub name: String,
+    pub aliases: Vec<String>,
+    pub docs: RustDocs,
     pub fields: field::VariantFields<'a>,
     pub serde_ast: &'a ast::Container<'a>,
-    pub attrs: &'a serde_derive_internals::attr::Variant,
+    pub unknown_keys: Option<UnknownKeys>,
 }
 
 impl<'a> StructVariant<'a> {
     pub fn expand_schema(&self) -> TokenStream {
-        let inner = match self.fields.len() {
-            0 => unreachable!("Empty tuple structs are handled by darling"),
-            1 => {
-                let inner = self.fields.expand_schema();
-                inner.first().expect("one field").clone()
-            }
-
-            _ => {
-                let inner = self.fields.expand_schema();
-                quote! {
-                    {
-                        let inner: std::vec::Vec<String> = vec![#(#inner),*];
-                        inner.join(", ")
-                    }
-                }
-            }
-        };
+        let zod = get_zod();
+        let inner = self.fields.expand_schema();
 
         let span = self.ident.span();
-        let name = self.attrs.name().deserialize_name();
+        let name = &self.name;
+
+        let unknown_keys = UnknownKeys::expand(self.unknown_keys);
+        let aliases = aliases::expand(&self.aliases);
+        let name_schema = aliases::expand_name_schema(name, &self.aliases);
+        let fields = quote_spanned! {span =>
+            #zod::ast::ZodObject::new(vec![#(#inner),*]) #unknown_keys
+        };
 
         match self.serde_ast.attrs.tag() {
             TagType::External => {
-                quote_spanned! {span =>  format!("z.object({{ {}: z.object({{ {} }}) }})", #name, #inner) }
+                quote_spanned! {span =>
+                    #zod::ast::ZodSchema::object(vec![
+                        #zod::ast::ZodField::new(#name, #fields.into()) #aliases
+                    ])
+                }
             }
             TagType::Internal { tag } => {
-                quote_spanned! {span =>  format!("z.object({{ {}: z.literal(\"{}\"), {} }})", #tag, #name, #inner) }
+                quote_spanned! {span =>
+                    #zod::ast::ZodObject::new(vec![
+                        #zod::ast::ZodField::new(#tag, #name_schema),
+                        #(#inner),*
+                    ])
+                    #unknown_keys
+                    .into()
+                }
             }
             TagType::Adjacent { tag, content } => {
-                quote_spanned! {span =>  format!("z.object({{ {}: z.literal(\"{}\"), {}: z.object({{ {} }}) }})", #tag, #name, #content, #inner) }
-            }
-            TagType::None => {
-                quote_spanned! {span =>  format!("z.object({{ {} }})", #inner) }
+                quote_spanned! {span =>
+                    #zod::ast::ZodSchema::object(vec![
+                        #zod::ast::ZodField::new(#tag, #name_schema),
+                        #zod::ast::ZodField::new(#content, #fields.into())
+                    ])
+                }
             }
+            TagType::None => quote_spanned! {span => #fields.into() },
         }
     }
 
     pub fn expand_type_defs(&self) -> TokenStream {
+        let zod = get_zod();
         let expanded_fields = self.fields.expand_type_defs();
-        let span = self.ident.span();
-        let name = self.attrs.name().deserialize_name();
-
-        let inner = match expanded_fields.len() {
-            0 => unreachable!("Empty tuple structs are handled by darling"),
-            1 => expanded_fields
-                .first()
-                .expect("exactly one variant")
-                .clone(),
 
-            _ => {
-                quote! {
-                    {
-                        let inner: std::vec::Vec<String> = vec![#(#expanded_fields),*];
-                        inner.join(", ")
-                    }
-                }
-            }
-        };
+        let span = self.ident.span();
+        let name = &self.name;
+        let name_type = aliases::expand_name_type(name, &self.aliases);
 
         match self.serde_ast.attrs.tag() {
             // `A{ num: usize, s: String }` -> `{ A: { num: number, s: string } }`
             TagType::External => {
-                quote_spanned! {span =>  format!("{{ {}: {{ {} }} }}", #name, #inner) }
+                quote_spanned! {span =>
+                    #zod::ast::TsType::Object(vec![
+                        #zod::ast::TsField::new(#name, #zod::ast::TsType::Object(vec![#(#expanded_fields),*]))
+                    ])
+                }
             }
             // `A{ num: usize, s: String }` -> `{ type: "A", num: number, s: string }`
             TagType::Internal { tag } => {
-                quote_spanned! {span =>  format!("{{ {}: \"{}\", {} }}", #tag, #name, #inner) }
+                quote_spanned! {span =>
+                    #zod::ast::TsType::Object(vec![
+                        #zod::ast::TsField::new(#tag, #name_type),
+                        #(#expanded_fields),*
+                    ])
+                }
             }
 
             // `A{ num: usize, s: String }` -> `{ type: "A", content: { num: number, s: string }}`
             TagType::Adjacent { tag, content } => {
-                quote_spanned! {span =>  format!("{{ {}: \"{}\", {}: {{ {} }} }}", #tag, #name, #content, #inner) }
+                quote_spanned! {span =>
+                    #zod::ast::TsType::Object(vec![
+                        #zod::ast::TsField::new(#tag, #name_type),
+                        #zod::ast::TsField::new(#content, #zod::ast::TsType::Object(vec![#(#expanded_fields),*]))
+                    ])
+                }
             }
             // `A{ num: usize, s: String }` -> `{ num: number, s: string }`
             TagType::None => {
-                quote_spanned! {span =>  format!("{{ {} }}", #inner) }
+                quote_spanned! {span => #zod::ast::TsType::Object(vec![#(#expanded_fields),*]) }
             }
         }
     }
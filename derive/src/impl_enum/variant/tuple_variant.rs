@@ -1,28 +1,37 @@
 use super::field;
 use super::UnitVariant;
+use crate::{aliases, docs::RustDocs, get_zod};
 use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
 use serde_derive_internals::attr::TagType;
 
-/// represents a tuple variant of an enum, it has one or more unnamed fields. It is represented as a tuple in
+/// represents a tuple variant of an enum, it has zero or more unnamed fields. It is represented as a tuple in
 /// zod which is a const array in typescript
 pub struct TupleVariant<'a> {
     pub span: Span,
     pub tag: &'a TagType,
     pub name: String,
+    pub aliases: Vec<String>,
+    pub docs: RustDocs,
     pub fields: field::VariantFields<'a>,
+    /// `A()` declares no fields and is serialized as an empty array
+    pub is_empty: bool,
 }
 
 impl<'a> TupleVariant<'a> {
     pub fn expand_schema(&self) -> TokenStream {
+        let zod = get_zod();
         let inner = match self.fields.len() {
+            0 if self.is_empty => quote!(#zod::ast::ZodSchema::Tuple(Vec::new())),
             0 => {
                 // may occur if fields are skipped. In this case we handle it like a unit variant
                 return UnitVariant {
                     span: self.span,
                     tag: self.tag,
                     name: self.name.clone(),
+                    aliases: self.aliases.clone(),
+                    docs: self.docs.clone(),
                 }
                 .expand_schema();
             }
@@ -35,43 +44,62 @@ impl<'a> TupleVariant<'a> {
 
             _ => {
                 let inner = self.fields.expand_schema();
-                quote! {
-                    {
-                        let inner: std::vec::Vec<String> = vec![#(#inner),*];
-                        format!("z.tuple([{}])", inner.join(", "))
-                    }
-                }
+                quote!(#zod::ast::ZodSchema::Tuple(vec![#(#inner),*]))
             }
         };
         let name = &self.name;
+        let aliases = aliases::expand(&self.aliases);
+        let name_schema = aliases::expand_name_schema(name, &self.aliases);
         let span = self.span;
 
         match self.tag {
-            TagType::External | TagType::Internal { .. } => {
-                quote_spanned! {span =>  format!("z.object({{ {}: {} }})", #name, #inner) }
+            TagType::External => {
+                quote_spanned! {span =>
+                    #zod::ast::ZodSchema::object(vec![
+                        #zod::ast::ZodField::new(#name, #inner) #aliases
+                    ])
+                }
             }
-            TagType::Adjacent { tag, content } => {
-                quote_spanned! {span =>  format!("z.object({{ {}: z.literal(\"{}\"), {}: {} }})", #tag, #name, #content, #inner) }
+            // serde only allows newtype variants wrapping a struct or map which receives the tag
+            TagType::Internal { tag } => {
+                quote_spanned! {span =>
+                    #zod::ast::ZodObject::new(vec![
+                        #zod::ast::ZodField::new(#tag, #name_schema)
+                    ])
+                    .extend(#inner)
+                    .into()
+                }
             }
-            TagType::None => {
-                quote_spanned! {span =>  String::from(#inner) }
+            TagType::Adjacent { tag, content } => {
+                quote_spanned! {span =>
+                    #zod::ast::ZodSchema::object(vec![
+                        #zod::ast::ZodField::new(#tag, #name_schema),
+                        #zod::ast::ZodField::new(#content, #inner)
+                    ])
+                }
             }
+            TagType::None => inner,
         }
     }
 
     pub fn expand_type_defs(&self) -> TokenStream {
+        let zod = get_zod();
         let expanded_fields = self.fields.expand_type_defs();
         let span = self.span;
         let tag_type = self.tag;
         let name = &self.name;
+        let name_type = aliases::expand_name_type(name, &self.aliases);
 
         let inner = match expanded_fields.len() {
+            0 if self.is_empty => quote!(#zod::ast::TsType::Tuple(Vec::new())),
             // may occur if fields are skipped. In this case we handle it like a unit variant
             0 => {
                 return UnitVariant {
                     span: self.span,
                     tag: self.tag,
                     name: self.name.clone(),
+                    aliases: self.aliases.clone(),
+                    docs: self.docs.clone(),
                 }
                 .expand_type_defs()
             }
@@ -80,26 +108,35 @@ impl<'a> TupleVariant<'a> {
                 .expect("exactly one variant")
                 .clone(),
 
-            _ => {
-                quote! {
-                    {
-                        let inner: std::vec::Vec<String> = vec![#(#expanded_fields),*];
-                        format!("[{}]", inner.join(", "))
-                    }
-                }
-            }
+            _ => quote!(#zod::ast::TsType::Tuple(vec![#(#expanded_fields),*])),
         };
 
         match tag_type {
-            TagType::External | TagType::Internal { .. } => {
-                quote_spanned! {span =>  format!("{{ {}: {} }}", #name, #inner) }
+            TagType::External => {
+                quote_spanned! {span =>
+                    #zod::ast::TsType::Object(vec![#zod::ast::TsField::new(#name, #inner)])
+                }
             }
-            TagType::Adjacent { tag, content } => {
-                quote_spanned! {span =>  format!("{{ {}: \"{}\", {}: {} }}", #tag, #name, #content, #inner) }
+            // `A(B)` -> `{ type: "A" } & B`
+            TagType::Internal { tag } => {
+                quote_spanned! {span =>
+                    #zod::ast::TsType::Intersection(vec![
+                        #zod::ast::TsType::Object(vec![
+                            #zod::ast::TsField::new(#tag, #name_type)
+                        ]),
+                        #inner
+                    ])
+                }
             }
-            TagType::None => {
-                quote_spanned! {span =>  #inner }
+            TagType::Adjacent { tag, content } => {
+                quote_spanned! {span =>
+                    #zod::ast::TsType::Object(vec![
+                        #zod::ast::TsField::new(#tag, #name_type),
+                        #zod::ast::TsField::new(#content, #inner)
+                    ])
+                }
             }
+            TagType::None => inner,
         }
     }
 }
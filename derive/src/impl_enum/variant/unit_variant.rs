@@ -2,48 +2,56 @@ use proc_macro2::{Span, TokenStream};
 use quote::quote_spanned;
 use serde_derive_internals::attr::TagType;
 
+use crate::{aliases, docs::RustDocs, get_zod};
+
 /// represents a unit variant of an enum, it has no fields and it is represented in typescript as the
 /// stringifyied name
 pub struct UnitVariant<'a> {
     pub span: Span,
     pub tag: &'a TagType,
     pub name: String,
+    pub aliases: Vec<String>,
+    pub docs: RustDocs,
 }
 
 impl<'a> UnitVariant<'a> {
     pub fn expand_schema(&self) -> TokenStream {
-        let name = &self.name;
+        let zod = get_zod();
+        let name = aliases::expand_name_schema(&self.name, &self.aliases);
         match self.tag {
             TagType::External => {
-                quote_spanned!(self.span => format!("z.literal(\"{}\")", #name))
-            }
-            TagType::Internal { tag } => {
-                quote_spanned!(self.span=> format!("z.object({{ {}: z.literal(\"{}\") }})", #tag, #name))
+                quote_spanned!(self.span => #name)
             }
-            TagType::Adjacent { tag, .. } => {
-                quote_spanned!(self.span=> format!("z.object({{ {}: z.literal(\"{}\") }})", #tag, #name))
+            TagType::Internal { tag } | TagType::Adjacent { tag, .. } => {
+                quote_spanned! {self.span =>
+                    #zod::ast::ZodSchema::object(vec![
+                        #zod::ast::ZodField::new(#tag, #name)
+                    ])
+                }
             }
             TagType::None => {
-                quote_spanned!(self.span => String::from("z.null()"))
+                quote_spanned!(self.span => #zod::ast::ZodSchema::Null)
             }
         }
     }
 
     /// Example `A`  ->  `"A"`
     pub fn expand_type_defs(&self) -> TokenStream {
-        let name = &self.name;
+        let zod = get_zod();
+        let name = aliases::expand_name_type(&self.name, &self.aliases);
         match self.tag {
             TagType::External => {
-                quote_spanned!(self.span => format!("\"{}\"", #name))
-            }
-            TagType::Internal { tag } => {
-                quote_spanned!(self.span => format!("{{ {}: \"{}\" }}", #tag, #name))
+                quote_spanned!(self.span => #name)
             }
-            TagType::Adjacent { tag, .. } => {
-                quote_spanned!(self.span => format!("{{ {}: \"{}\" }}", #tag, #name))
+            TagType::Internal { tag } | TagType::Adjacent { tag, .. } => {
+                quote_spanned! {self.span =>
+                    #zod::ast::TsType::Object(vec![
+                        #zod::ast::TsField::new(#tag, #name)
+                    ])
+                }
             }
             TagType::None => {
-                quote_spanned!(self.span => String::from("null"))
+                quote_spanned!(self.span => #zod::ast::TsType::Null)
             }
         }
     }
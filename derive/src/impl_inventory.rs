@@ -2,11 +2,33 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Ident, Path};
 
+use crate::generics::ZodGenerics;
 use crate::get_zod;
 
-pub fn expand(ident: &Ident, ns_path: &Path, name: &str) -> TokenStream {
+pub fn expand(
+    ident: &Ident,
+    ns_path: &Path,
+    name: &str,
+    input_name: &str,
+    generics: &ZodGenerics,
+) -> TokenStream {
     let zod = get_zod();
-    quote! {
-        #zod::__private::inventory::submit!(#zod::NamespaceMemberDefinition::new_for::<#ident>(<#ns_path as #zod::Namespace>::NAME, #name));
+    let instance = generics.expand_placeholder_instance(ident);
+
+    let output = quote!(#zod::NamespaceMemberDefinition::new_for::<#instance>(<#ns_path as #zod::Namespace>::NAME, #name));
+    let input = quote!(#zod::NamespaceMemberDefinition::new_input_for::<#instance>(<#ns_path as #zod::Namespace>::NAME, #input_name));
+
+    if generics.is_empty() {
+        quote! {
+            #zod::__private::inventory::submit!(#output);
+            #zod::__private::inventory::submit!(#input);
+        }
+    } else {
+        let names = generics.names();
+
+        quote! {
+            #zod::__private::inventory::submit!(#output.with_generics(&[#(#names),*]));
+            #zod::__private::inventory::submit!(#input.with_generics(&[#(#names),*]));
+        }
     }
 }
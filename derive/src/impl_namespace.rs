@@ -8,6 +8,7 @@ pub fn expand(
 ) -> proc_macro2::TokenStream {
     let ident = input.ident;
     let name = input.name.unwrap_or_else(|| ident.to_string());
+    crate::type_name::check(&name, ident.span());
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
     let p = parse_quote!(#ident);
@@ -1,11 +1,18 @@
-use crate::{docs::RustDocs, expand_type_registration, get_zod, impl_inventory};
+use crate::{
+    aliases, direction::Direction, docs::RustDocs, expand_type_registration, generics::ZodGenerics,
+    get_zod, impl_inventory, integer_mode, option_mode::FieldModifiers,
+    schema_override::SchemaOverride, unknown_keys::UnknownKeys, validation,
+};
 
 use super::args;
-use darling::ast::{Fields, Style};
+use darling::{
+    ast::{Fields, Style},
+    util::Override,
+};
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
 use quote::{quote, quote_spanned};
-use serde_derive_internals::ast;
+use serde_derive_internals::{ast, attr::TagType};
 use syn::{spanned::Spanned, Ident, Path};
 
 fn qualified_ty(ty: &syn::Type) -> proc_macro2::TokenStream {
@@ -15,157 +22,327 @@ fn qualified_ty(ty: &syn::Type) -> proc_macro2::TokenStream {
 
 pub fn expand(
     input: args::Input,
-    fields: Fields<args::StructField>,
-    serde_ast: ast::Container,
+    fields: Fields<args::Field>,
+    serde_output: ast::Container,
+    serde_input: ast::Container,
     docs: RustDocs,
 ) -> proc_macro2::TokenStream {
-    let transparent = serde_ast.attrs.transparent();
+    let mut output = Shape::new(Direction::Output, &input, &fields, &serde_output);
+    let mut input_shape = Shape::new(Direction::Input, &input, &fields, &serde_input);
 
-    let ident = input.ident;
-    let name = serde_ast.attrs.name().deserialize_name();
-    let ns_path = input.namespace;
+    // both directions share the brand, so inputs are assignable to outputs
+    if let Some(brand) = &input.brand {
+        if !output.is_newtype() {
+            abort!(input.ident.span(), "zod: `brand` requires a newtype struct")
+        }
 
-    let fields_ast = match serde_ast.data {
-        ast::Data::Enum(_) => unreachable!(),
-        ast::Data::Struct(_, fields) => fields,
-    };
+        let name = match brand {
+            Override::Explicit(name) => name.clone(),
+            Override::Inherit => output.name.clone(),
+        };
 
-    let style = fields.style;
-
-    let fields = fields
-        .iter()
-        .zip(fields_ast.iter().map(|f| &f.attrs))
-        .filter(|(_, attrs)| !attrs.skip_deserializing())
-        .map(|(args::StructField { ty, ident }, attrs)| StructField {
-            ty,
-            name: ident.as_ref().map(|_| attrs.name().deserialize_name()),
-            optional: !attrs.default().is_none(),
-            transparent,
-            flatten: attrs.flatten(),
-        })
-        .collect();
-
-    let from_ty = serde_ast
-        .attrs
-        .type_from()
-        .or_else(|| serde_ast.attrs.type_try_from())
-        .cloned();
+        output.brand = Some(name.clone());
+        input_shape.brand = Some(name);
+    }
 
     let struct_def = Struct {
-        transparent,
-        ns_path,
-        name,
+        ident: input.ident.clone(),
+        generics: ZodGenerics::new(&input.generics),
+        map_key: input.map_key.is_present(),
+        ns_path: input.namespace.clone(),
         docs,
-        ident,
-        fields,
-        style,
-        from_ty,
+        output,
+        input: input_shape,
     };
 
     struct_def.expand()
 }
 
 struct Struct<'a> {
-    transparent: bool,
     ident: Ident,
+    generics: ZodGenerics,
+    /// `#[zod(map_key)]`
+    map_key: bool,
     ns_path: Path,
     docs: RustDocs,
-    name: String,
-    fields: Vec<StructField<'a>>,
-    style: Style,
-    from_ty: Option<syn::Type>,
+    output: Shape<'a>,
+    input: Shape<'a>,
 }
 
 impl<'a> Struct<'a> {
     fn expand(&self) -> TokenStream {
-        let schema = self.expand_schema();
-        let type_def = self.expand_type_def();
         let ident = &self.ident;
         let ns_path = &self.ns_path;
-        let name = &self.name;
         let docs = &self.docs;
 
+        let instance = self.generics.expand_placeholder_instance(ident);
+        let placeholders = self.generics.expand_placeholders();
+        let output = self
+            .output
+            .expand(ns_path, &self.generics, &instance, &self.output.name);
+        let input = self
+            .input
+            .expand(ns_path, &self.generics, &instance, &self.output.name);
+        let map_key = self.expand_map_key();
+
         let type_register = expand_type_registration(ident, ns_path);
-        let inventory = impl_inventory::expand(ident, ns_path, name);
+        let inventory = impl_inventory::expand(
+            ident,
+            ns_path,
+            &self.output.name,
+            &self.input.name,
+            &self.generics,
+        );
+        let (impl_generics, ty_generics, where_clause) = self.generics.generics().split_for_impl();
 
         let zod = get_zod();
-        if let Some(t) = &self.from_ty {
-            quote! {
-                impl #zod::ZodType for #ident {
-                    fn schema() -> String {
-                        <#t as #zod::ZodType>::inline_schema()
-                    }
 
-                    fn inline_schema() -> String {
-                        format!("z.lazy(() => {}.{})",
-                            <#ns_path as #zod::Namespace>::NAME,
-                            #name
-                        )
-                    }
+        quote! {
+            #placeholders
 
+            impl #impl_generics #zod::ZodType for #ident #ty_generics #where_clause {
+                #output
 
-                    fn type_def() -> #zod::TsTypeDef {
-                        #zod::TsTypeDef::Type(<#t as #zod::ZodType>::inline().to_string())
-                    }
+                #input
 
-                    fn inline() -> #zod::InlinedType {
-                        <#t as #zod::ZodType>::inline()
-                    }
+                fn docs() -> Option<&'static str> {
+                    Some(#docs)
+                }
+            }
 
-                    fn docs() -> Option<&'static str> {
-                        Some(#docs)
-                    }
+            #map_key
+
+            #inventory
+
+            #type_register
+        }
+    }
+
+    /// Newtypes serialize like the wrapped type and are valid map keys whenever it is one. A bound
+    /// on a concrete type which is not a key would fail to compile (rust-lang/rust#48214), so
+    /// newtypes over concrete types opt in with `#[zod(map_key)]`.
+    fn expand_map_key(&self) -> TokenStream {
+        let shape = &self.output;
+        let newtype = match (shape.transparent, shape.style, shape.fields.as_slice()) {
+            (true, _, _) => shape.fields.iter().find(|f| !f.flatten),
+            (false, Style::Tuple, [field]) => Some(field),
+            _ => None,
+        };
+
+        let zod = get_zod();
+        let ident = &self.ident;
+
+        let Some(field) = newtype else {
+            if self.map_key {
+                abort!(ident.span(), "zod: `map_key` requires a newtype struct")
+            }
+            return TokenStream::new();
+        };
+
+        let inner = field.ty;
+        let mut generics = self.generics.generics().clone();
+
+        if self.generics.is_used_in(inner) {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(#inner: #zod::ZodMapKey));
+        } else if !self.map_key {
+            return TokenStream::new();
+        }
+
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        quote! {
+            impl #impl_generics #zod::ZodMapKey for #ident #ty_generics #where_clause {
+                fn key_schema() -> #zod::ast::ZodSchema {
+                    <#inner as #zod::ZodMapKey>::key_schema()
+                }
+
+                fn key_type() -> #zod::ast::TsType {
+                    <#inner as #zod::ZodMapKey>::key_type()
+                }
+
+                fn finite() -> bool {
+                    <#inner as #zod::ZodMapKey>::finite()
+                }
+            }
+        }
+    }
+}
+
+/// The shape of a struct in one [Direction]
+struct Shape<'a> {
+    direction: Direction,
+    transparent: bool,
+    name: String,
+    fields: Vec<StructField<'a>>,
+    style: Style,
+    proxy_ty: Option<syn::Type>,
+    unknown_keys: Option<UnknownKeys>,
+    /// `#[zod(brand)]`, the name of the nominal type
+    brand: Option<String>,
+    /// `#[serde(tag = "..")]`, the key and the value of the tag field
+    tag: Option<(String, String)>,
+}
+
+impl<'a> Shape<'a> {
+    fn new(
+        direction: Direction,
+        input: &args::Input,
+        fields: &'a Fields<args::Field>,
+        serde_ast: &ast::Container,
+    ) -> Self {
+        let fields_ast = match &serde_ast.data {
+            ast::Data::Enum(_) => unreachable!(),
+            ast::Data::Struct(_, fields) => fields,
+        };
+
+        let struct_fields = fields
+            .iter()
+            .zip(fields_ast.iter().map(|f| &f.attrs))
+            .filter(|(_, attrs)| !direction.skip_field(attrs))
+            .map(|(field, attrs)| {
+                let integer_mode = integer_mode::expand(field, attrs);
+
+                StructField {
+                    ty: &field.ty,
+                    direction,
+                    name: field.ident.as_ref().map(|_| direction.name(attrs.name())),
+                    aliases: aliases::aliases(direction, attrs.name(), attrs.aliases()),
+                    refinements: validation::expand_refinements(field),
+                    modifiers: FieldModifiers::new(direction, input.option, field, attrs),
+                    flatten: attrs.flatten(),
+                    integer_mode,
+                    schema_override: SchemaOverride::new(field),
+                    docs: RustDocs::of(&field.attrs),
+                }
+            })
+            .collect();
+
+        Self {
+            direction,
+            transparent: serde_ast.attrs.transparent(),
+            name: direction.member_name(&serde_ast.attrs, input.remote.as_ref()),
+            fields: struct_fields,
+            style: fields.style,
+            proxy_ty: direction.proxy_type(&serde_ast.attrs),
+            unknown_keys: UnknownKeys::resolve(input.unknown_keys, &serde_ast.attrs),
+            brand: None,
+            tag: match serde_ast.attrs.tag() {
+                TagType::Internal { tag } => {
+                    Some((tag.clone(), direction.name(serde_ast.attrs.name())))
+                }
+                _ => None,
+            },
+        }
+    }
+
+    /// Serde adds the tag to serialized structs but ignores it while deserializing, inputs
+    /// therefore accept objects without it.
+    fn expand_tag_field(&self) -> Option<(TokenStream, TokenStream)> {
+        let zod = get_zod();
+        let (tag, name) = self.tag.as_ref()?;
+        let optional = matches!(self.direction, Direction::Input).then(|| quote!(.optional()));
+
+        Some((
+            quote!(#zod::ast::ZodField::new(#tag, #zod::ast::ZodSchema::literal(#name) #optional)),
+            quote!(#zod::ast::TsField::new(#tag, #zod::ast::TsType::literal(#name)) #optional),
+        ))
+    }
+
+    /// the struct is represented like its only field
+    fn is_newtype(&self) -> bool {
+        let fields = self.fields.iter().filter(|f| !f.flatten).count();
+        self.proxy_ty.is_none() && (self.transparent || (self.style == Style::Tuple && fields == 1))
+    }
+
+    /// the `ZodType` methods of this direction, `instance` is the registered instance of the type
+    fn expand(
+        &self,
+        ns_path: &Path,
+        generics: &ZodGenerics,
+        instance: &TokenStream,
+        output_name: &str,
+    ) -> TokenStream {
+        let zod = get_zod();
+        let name = self
+            .direction
+            .expand_ref_name(instance, output_name, &self.name);
+        let schema_args = generics.expand_schema_args(self.direction);
+        let type_args = generics.expand_type_args(self.direction);
+
+        let schema_fn = self.direction.schema();
+        let inline_schema_fn = self.direction.inline_schema();
+        let type_def_fn = self.direction.type_def();
+        let inline_fn = self.direction.inline();
+
+        let inline_schema = quote! {
+            #zod::ast::ZodSchema::Lazy {
+                ns_name: <#ns_path as #zod::Namespace>::NAME,
+                name: #name,
+                args: #schema_args
+            }
+        };
+
+        if let Some(t) = &self.proxy_ty {
+            quote! {
+                fn #schema_fn() -> #zod::ast::ZodSchema {
+                    <#t as #zod::ZodType>::#inline_schema_fn()
                 }
 
-                #inventory
+                fn #inline_schema_fn() -> #zod::ast::ZodSchema {
+                    #inline_schema
+                }
 
-                #type_register
+                fn #type_def_fn() -> #zod::TsTypeDef {
+                    #zod::TsTypeDef::Type(<#t as #zod::ZodType>::#inline_fn().into())
+                }
 
+                fn #inline_fn() -> #zod::InlinedType {
+                    <#t as #zod::ZodType>::#inline_fn()
+                }
             }
         } else {
+            let mut schema = self.expand_schema();
+            let mut type_def = self.expand_type_def();
+
+            if let Some(brand) = &self.brand {
+                schema = quote!(#schema.brand(#brand));
+                type_def = quote!(#type_def.branded(#brand));
+            }
+
             let interface_or_type = match (self.fields.len() == 1, self.style, self.transparent) {
                 (_, Style::Unit, _) | (true, Style::Tuple, _) | (_, _, true) => quote!(Type),
                 _ => quote!(Interface),
             };
 
             quote! {
-                impl #zod::ZodType for #ident {
-                    fn schema() -> String {
-                        #schema
-                    }
-
-                    fn inline_schema() -> String {
-                        format!("z.lazy(() => {}.{})",
-                            <#ns_path as #zod::Namespace>::NAME,
-                            #name
-                        )
-                    }
+                fn #schema_fn() -> #zod::ast::ZodSchema {
+                    #schema
+                }
 
-                    fn type_def() -> #zod::TsTypeDef {
-                        #zod::TsTypeDef:: #interface_or_type ({ #type_def })
-                    }
+                fn #inline_schema_fn() -> #zod::ast::ZodSchema {
+                    #inline_schema
+                }
 
-                    fn inline() -> #zod::InlinedType {
-                        #zod::InlinedType::Ref {
-                            ns_name: <#ns_path as #zod::Namespace>::NAME,
-                            name: #name
-                        }
-                    }
+                fn #type_def_fn() -> #zod::TsTypeDef {
+                    #zod::TsTypeDef:: #interface_or_type (#type_def)
+                }
 
-                    fn docs() -> Option<&'static str> {
-                        Some(#docs)
+                fn #inline_fn() -> #zod::InlinedType {
+                    #zod::InlinedType::Ref {
+                        ns_name: <#ns_path as #zod::Namespace>::NAME,
+                        name: #name,
+                        args: #type_args
                     }
                 }
-
-                #inventory
-
-                #type_register
-
             }
         }
     }
 
     fn expand_type_def(&self) -> TokenStream {
+        let zod = get_zod();
         let (flat_fields, fields) = self.fields.iter().partition::<Vec<_>, _>(|f| f.flatten);
 
         match (self.transparent, self.style) {
@@ -173,42 +350,52 @@ impl<'a> Struct<'a> {
                 .into_iter()
                 .next()
                 .expect("At least one field")
-                .expand_type_defs(),
+                .expand_type_def(),
 
+            // serde serializes `struct Empty();` as `[]`
             (false, Style::Tuple) => match fields.len() {
-                0 => unreachable!("handled by darling"),
                 1 => fields
                     .into_iter()
                     .next()
                     .or_else(|| flat_fields.into_iter().next())
                     .expect("Newtype")
-                    .expand_type_defs(),
+                    .expand_type_def(),
                 _ => {
-                    let fields = fields.into_iter().map(|f| f.expand_type_defs());
+                    let fields = fields.into_iter().map(|f| f.expand_type_def());
 
                     quote! {
-                        let fields: Vec<String> = vec![#(#fields),*];
-                        format!("[{}]", fields.join(", "))
+                        #zod::ast::TsType::Tuple(vec![#(#fields),*])
                     }
                 }
             },
 
             (false, Style::Struct) => {
-                let fields = fields.into_iter().map(|f| f.expand_type_defs());
-                let flat_fields = flat_fields.into_iter().map(|f| f.expand_type_defs());
-
-                quote! {
-                    let fields: Vec<String> = vec![#(#fields),*];
-                    let extensions: Vec<String> = vec![#(#flat_fields),*];
-                    format!("{{{}}}{}", fields.join(",\n"), extensions.join(""))
+                let tag = self.expand_tag_field().map(|(_, ty)| ty);
+                let fields = tag
+                    .into_iter()
+                    .chain(fields.into_iter().map(|f| f.expand_ts_field()));
+                let object = quote!(#zod::ast::TsType::Object(vec![#(#fields),*]));
+
+                if flat_fields.is_empty() {
+                    object
+                } else {
+                    let flat_fields = flat_fields.into_iter().map(|f| {
+                        let ty = f.expand_type_def();
+                        quote!(#ty.flattened())
+                    });
+                    quote! {
+                        #zod::ast::TsType::Intersection(vec![#object, #(#flat_fields),*])
+                    }
                 }
             }
 
-            (false, Style::Unit) => unreachable!(),
+            // unit structs are serialized as `null`
+            (false, Style::Unit) => quote!(#zod::ast::TsType::Null),
         }
     }
 
     fn expand_schema(&self) -> TokenStream {
+        let zod = get_zod();
         let (flat_fields, fields) = self.fields.iter().partition::<Vec<_>, _>(|f| f.flatten);
 
         match (self.transparent, self.style) {
@@ -219,7 +406,6 @@ impl<'a> Struct<'a> {
                 .expand_schema(),
 
             (false, Style::Tuple) => match fields.len() {
-                0 => unreachable!("handled by darling"),
                 1 => fields
                     .into_iter()
                     .next()
@@ -230,8 +416,8 @@ impl<'a> Struct<'a> {
                     // make sure all fields followed an optional field are also optional
                     if let Some(f) = fields
                         .iter()
-                        .skip_while(|f| !f.optional)
-                        .find(|f| !f.optional)
+                        .skip_while(|f| !f.modifiers.is_optional())
+                        .find(|f| !f.modifiers.is_optional())
                     {
                         abort!(f.ty.span(), "zod: non-default field follows default field")
                     }
@@ -239,122 +425,106 @@ impl<'a> Struct<'a> {
                     let fields = fields.into_iter().map(|f| f.expand_schema());
 
                     quote! {
-                        let fields: Vec<String> = vec![#(#fields),*];
-                        format!("z.tuple([{}])", fields.join(", "))
+                        #zod::ast::ZodSchema::Tuple(vec![#(#fields),*])
                     }
                 }
             },
 
             (false, Style::Struct) => {
-                let fields = fields.into_iter().map(|f| f.expand_schema());
+                let tag = self.expand_tag_field().map(|(schema, _)| schema);
+                let fields = tag
+                    .into_iter()
+                    .chain(fields.into_iter().map(|f| f.expand_zod_field()));
                 let flat_fields = flat_fields.into_iter().map(|f| f.expand_schema());
+                let unknown_keys = UnknownKeys::expand(self.unknown_keys);
 
                 quote! {
-                    let fields: Vec<String> = vec![#(#fields),*];
-                    let extensions: Vec<String> = vec![#(#flat_fields),*];
-                    format!("z.object({{{}}}){}", fields.join(",\n"), extensions.join(""))
+                    #zod::ast::ZodObject::new(vec![#(#fields),*])
+                        #(.extend(#flat_fields))*
+                        #unknown_keys
+                        .into()
                 }
             }
 
-            (false, Style::Unit) => unreachable!(),
+            (false, Style::Unit) => quote!(#zod::ast::ZodSchema::Null),
         }
     }
 }
 
 struct StructField<'a> {
     name: Option<String>,
+    aliases: Vec<String>,
     ty: &'a syn::Type,
-    optional: bool,
-    transparent: bool,
+    direction: Direction,
+    modifiers: FieldModifiers,
+    refinements: TokenStream,
     flatten: bool,
+    /// `.with_integer_mode(...)`, see [integer_mode::expand]
+    integer_mode: Option<TokenStream>,
+    /// replaces the schema of the field type
+    schema_override: Option<SchemaOverride<'a>>,
+    docs: RustDocs,
 }
 
 impl<'a> StructField<'a> {
+    /// the schema of the field value
     fn expand_schema(&self) -> TokenStream {
-        let maybe_optional = self.expand_optional_schema();
         let ty = qualified_ty(self.ty);
+        let inline_schema = self.direction.inline_schema();
+        let refinements = &self.refinements;
+        let integer_mode = &self.integer_mode;
+        let modifiers = self.modifiers.expand_schema();
+
+        if let Some(schema_override) = &self.schema_override {
+            let schema = schema_override.expand_schema(self.direction);
+            quote_spanned! {ty.span() => #schema #integer_mode #refinements #modifiers }
+        } else {
+            quote_spanned! {ty.span() => #ty::#inline_schema() #integer_mode #refinements #modifiers }
+        }
+    }
 
-        match (self.flatten, &self.name, self.transparent) {
-            (false, Some(name), false) => {
-                quote_spanned! {ty.span() =>  format!("{}: {}{}", #name, #ty::inline_schema(), #maybe_optional) }
-            }
-            (false, Some(_), true) => {
-                quote_spanned! {ty.span() =>  format!("{}{}", #ty::inline_schema(), #maybe_optional) }
-            }
-            (false, None, _) => {
-                quote_spanned! { ty.span() => format!("{}{}", #ty::inline_schema(), #maybe_optional) }
-            }
+    /// the named field of a `z.object({ ... })`
+    fn expand_zod_field(&self) -> TokenStream {
+        let zod = get_zod();
+        let name = self.name.as_ref().expect("named field");
+        let schema = self.expand_schema();
+        let aliases = aliases::expand(&self.aliases);
+        let describe = self.docs.expand_describe();
 
-            (true, Some(_), false) => {
-                quote_spanned! {ty.span() =>  format!(".extend({}{})", #ty::inline_schema(), #maybe_optional) }
-            }
-            (true, Some(_), true) => {
-                quote_spanned! {ty.span() =>  format!(".extend({}{})", #ty::inline_schema(), #maybe_optional) }
-            }
-            (true, None, _) => {
-                // Newtype
-                quote_spanned! { ty.span() => format!(".extend({}{})", #ty::inline_schema(), #maybe_optional) }
-            }
-        }
+        quote_spanned! {self.ty.span() => #zod::ast::ZodField::new(#name, #schema) #aliases #describe }
     }
 
-    fn expand_optional_schema(&self) -> TokenStream {
-        if self.optional {
-            quote!(".optional()")
+    /// the typescript type of the field value without modifiers
+    fn expand_value_type(&self) -> TokenStream {
+        let zod = get_zod();
+        let ty = qualified_ty(self.ty);
+        let inline = self.direction.inline();
+        let integer_mode = &self.integer_mode;
+
+        if let Some(schema_override) = &self.schema_override {
+            let value = schema_override.expand_type(self.direction);
+            quote_spanned! {ty.span() => #value #integer_mode }
         } else {
-            quote!("")
+            quote_spanned! {ty.span() => #zod::ast::TsType::from(#ty::#inline()) #integer_mode }
         }
     }
 
-    fn expand_type_defs(&self) -> TokenStream {
-        let ty = qualified_ty(self.ty);
-
-        match (self.flatten, &self.name, self.optional, self.transparent) {
-            (false, Some(name), false, false) => {
-                quote_spanned! {ty.span() =>  format!("{}: {}", #name, #ty::inline()) }
-            }
-            (false, None, false, false) => {
-                // Newtype
-                quote_spanned! {ty.span() => #ty::inline().to_string()}
-            }
-            (false, Some(name), true, false) => {
-                quote_spanned! {ty.span() =>  format!("{}?: {} | undefined", #name, #ty::inline()) }
-            }
-            (false, None, true, false) => {
-                // Newtype
-                quote_spanned! {ty.span() => format!("{} | undefined", #ty::inline())}
-            }
+    /// the typescript type of the field value
+    fn expand_type_def(&self) -> TokenStream {
+        let value = self.expand_value_type();
+        let modifiers = self.modifiers.expand();
 
-            (false, _, false, true) => {
-                // Newtype
-                quote_spanned! {ty.span() => #ty::inline().to_string()}
-            }
-            (false, _, true, true) => {
-                quote_spanned! {ty.span() =>  format!("{} | undefined", #ty::inline()) }
-            }
+        quote_spanned! {self.ty.span() => #value #modifiers }
+    }
 
-            (true, Some(_), false, false) => {
-                quote_spanned! {ty.span() =>  format!(" & {}", #ty::inline()) }
-            }
-            (true, None, false, false) => {
-                // Newtype
-                quote_spanned! {ty.span() => #ty::inline().to_string()}
-            }
-            (true, Some(_), true, false) => {
-                quote_spanned! {ty.span() =>  format!("& ({} | undefined)", #ty::inline()) }
-            }
-            (true, None, true, false) => {
-                // Newtype
-                quote_spanned! {ty.span() => format!("& ({} | undefined)", #ty::inline())}
-            }
+    /// the named field of a typescript object type
+    fn expand_ts_field(&self) -> TokenStream {
+        let zod = get_zod();
+        let name = self.name.as_ref().expect("named field");
+        let value = self.expand_value_type();
+        let modifiers = self.modifiers.expand();
+        let with_docs = self.docs.expand_with_docs();
 
-            (true, _, false, true) => {
-                // Newtype
-                quote_spanned! {ty.span() => #ty::inline().to_string()}
-            }
-            (true, _, true, true) => {
-                quote_spanned! {ty.span() =>  format!("& ({} | undefined)", #ty::inline()) }
-            }
-        }
+        quote_spanned! {self.ty.span() => #zod::ast::TsField::new(#name, #value) #modifiers #with_docs }
     }
 }
@@ -0,0 +1,181 @@
+use crate::{args, get_zod, validation};
+use darling::ast::Style;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use serde_derive_internals::{
+    ast::{self, Data},
+    attr::TagType,
+};
+use syn::{parse_quote, Member};
+
+/// `#[zod(validate)]` implements `Validate` which checks the refinements of every field against
+/// the deserialized value. Errors are reported at the JSON path of the input flavour.
+pub fn expand(input: &args::Input, serde_input: &ast::Container) -> TokenStream {
+    if !input.validate.is_present() {
+        return TokenStream::new();
+    }
+
+    let zod = get_zod();
+    let ident = &input.ident;
+
+    // autoref dispatch is resolved where the impl is defined, without a bound fields of a generic
+    // type would never be validated
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#zod::validation::Validate));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match (&input.data, &serde_input.data) {
+        (darling::ast::Data::Struct(fields), Data::Struct(_, fields_ast)) => {
+            expand_struct(fields, fields_ast, serde_input.attrs.transparent())
+        }
+        (darling::ast::Data::Enum(variants), Data::Enum(variants_ast)) => {
+            expand_enum(variants, variants_ast, serde_input.attrs.tag())
+        }
+        _ => unreachable!(),
+    };
+
+    quote! {
+        impl #impl_generics #zod::validation::Validate for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                path: &[#zod::validation::PathSegment],
+                errors: &mut #zod::validation::ValidationErrors
+            ) {
+                use #zod::validation::__private::{ViaNoop as _, ViaValidate as _};
+                #body
+            }
+        }
+    }
+}
+
+fn expand_struct(
+    fields: &darling::ast::Fields<args::Field>,
+    fields_ast: &[ast::Field],
+    transparent: bool,
+) -> TokenStream {
+    let newtype = fields.style == Style::Tuple && fields.len() == 1;
+
+    let checks = fields
+        .iter()
+        .zip(fields_ast)
+        .filter(|(_, ast)| !ast.attrs.skip_deserializing())
+        .map(|(field, ast)| {
+            let value = match &ast.member {
+                Member::Named(ident) => quote!(&self.#ident),
+                Member::Unnamed(index) => quote!(&self.#index),
+            };
+
+            let segment = if transparent || newtype || ast.attrs.flatten() {
+                None
+            } else {
+                Some(segment(ast))
+            };
+
+            expand_field(field, value, segment.into_iter().collect())
+        });
+
+    quote!(#(#checks)*)
+}
+
+fn expand_enum(
+    variants: &[args::EnumVariant],
+    variants_ast: &[ast::Variant],
+    tag: &TagType,
+) -> TokenStream {
+    let arms = variants
+        .iter()
+        .zip(variants_ast)
+        .filter(|(_, ast)| !ast.attrs.skip_deserializing())
+        .map(|(variant, ast)| {
+            let ident = &variant.ident;
+            let bindings = (0..ast.fields.len())
+                .map(|i| format_ident!("__field{}", i))
+                .collect::<Vec<_>>();
+
+            let pattern = match ast.style {
+                ast::Style::Unit => quote!(Self::#ident),
+                ast::Style::Struct => {
+                    let members = ast.fields.iter().map(|f| &f.member);
+                    quote!(Self::#ident { #(#members: #bindings),* })
+                }
+                ast::Style::Tuple | ast::Style::Newtype => quote!(Self::#ident(#(#bindings),*)),
+            };
+
+            let name = ast.attrs.name().deserialize_name();
+            let prefix = match tag {
+                TagType::External => vec![quote!(#name)],
+                TagType::Adjacent { content, .. } => vec![quote!(#content)],
+                TagType::Internal { .. } | TagType::None => Vec::new(),
+            };
+
+            let checks = variant
+                .fields
+                .iter()
+                .zip(&ast.fields)
+                .zip(&bindings)
+                .filter(|((_, field_ast), _)| !field_ast.attrs.skip_deserializing())
+                .map(|((field, field_ast), binding)| {
+                    let mut segments = prefix.clone();
+
+                    if !matches!(ast.style, ast::Style::Newtype) && !field_ast.attrs.flatten() {
+                        segments.push(segment(field_ast));
+                    }
+
+                    expand_field(field, quote!(#binding), segments)
+                });
+
+            quote! {
+                #pattern => {
+                    #(#checks)*
+                }
+            }
+        });
+
+    quote! {
+        #[allow(unreachable_patterns)]
+        match self {
+            #(#arms,)*
+            _ => {}
+        }
+    }
+}
+
+/// the key or index of a field in JSON
+fn segment(field: &ast::Field) -> TokenStream {
+    match &field.member {
+        Member::Named(_) => {
+            let name = field.attrs.name().deserialize_name();
+            quote!(#name)
+        }
+        Member::Unnamed(index) => {
+            let index = index.index as usize;
+            quote!(#index)
+        }
+    }
+}
+
+fn expand_field(
+    field: &args::Field,
+    value: TokenStream,
+    segments: Vec<TokenStream>,
+) -> TokenStream {
+    let zod = get_zod();
+    let refinements = validation::refinements(field);
+
+    let path = segments.into_iter().fold(
+        quote!(path.to_vec()),
+        |path, segment| quote!(#zod::validation::__private::join(&#path, #segment)),
+    );
+
+    quote! {
+        {
+            let value = #value;
+            let path = #path;
+            #(#zod::validation::check(value, &#refinements, &path, errors);)*
+            (&#zod::validation::__private::Probe(value)).validate_at(&path, errors);
+        }
+    }
+}
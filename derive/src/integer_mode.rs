@@ -0,0 +1,51 @@
+use crate::{args::Field, get_zod};
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::quote;
+use serde_derive_internals::attr;
+use syn::spanned::Spanned;
+
+/// `#[zod(bigint)]` or `#[zod(as_string)]` represent the wide integers of a field as `bigint` or as
+/// a decimal string. The returned method call is applied to the `ZodSchema` and the `TsType` of the
+/// field value.
+///
+/// Serde sends integers as JSON numbers, both modes therefore require the field to be
+/// (de)serialized with `zod::bigint::as_string`.
+pub fn expand(field: &Field, attrs: &attr::Field) -> Option<TokenStream> {
+    let zod = get_zod();
+
+    let (mode, name) = match (field.bigint.is_present(), field.as_string.is_present()) {
+        (true, true) => abort!(
+            field.ty.span(),
+            "zod: `bigint` and `as_string` are mutually exclusive"
+        ),
+        (true, false) => (quote!(BigInt), "bigint"),
+        (false, true) => (quote!(String), "as_string"),
+        (false, false) => return None,
+    };
+
+    let uses_helper = |path: Option<&syn::ExprPath>, function: &str| {
+        path.is_some_and(|path| {
+            let segments: Vec<_> = path
+                .path
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect();
+
+            segments.ends_with(&["bigint".into(), "as_string".into(), function.into()])
+        })
+    };
+
+    if !uses_helper(attrs.serialize_with(), "serialize")
+        || !uses_helper(attrs.deserialize_with(), "deserialize")
+    {
+        abort!(
+            field.ty.span(),
+            "zod: `{}` requires `#[serde(with = \"zod::bigint::as_string\")]`",
+            name
+        )
+    }
+
+    Some(quote!(.with_integer_mode(#zod::ast::IntegerMode::#mode)))
+}
@@ -6,19 +6,29 @@ mod rpc;
 #[cfg(feature = "rpc")]
 use quote::quote;
 
+mod aliases;
 mod args;
+mod direction;
 mod docs;
+mod generics;
 mod impl_enum;
 mod impl_inventory;
 mod impl_namespace;
 mod impl_struct;
+mod impl_validate;
+mod integer_mode;
+mod option_mode;
+mod schema_override;
+mod type_name;
+mod unknown_keys;
+mod validation;
 
 use darling::{ast::Data, FromDeriveInput};
+use direction::Direction;
 use docs::RustDocs;
 use proc_macro::TokenStream;
 use proc_macro_error::proc_macro_error;
 use quote::{format_ident, quote_spanned};
-use serde_derive_internals::Derive;
 use syn::{Ident, Path};
 
 use proc_macro2::Span;
@@ -66,13 +76,8 @@ pub fn zod(input: TokenStream) -> TokenStream {
         }
     };
 
-    let cx = serde_derive_internals::Ctxt::new();
-
-    let container =
-        serde_derive_internals::ast::Container::from_ast(&cx, &parsed, Derive::Deserialize)
-            .unwrap();
-
-    cx.check().unwrap();
+    let serde_output = parse_serde_container(&parsed, Direction::Output);
+    let serde_input = parse_serde_container(&parsed, Direction::Input);
 
     let docs = match RustDocs::from_attrs(&parsed.attrs) {
         Ok(docs) => docs,
@@ -88,11 +93,43 @@ pub fn zod(input: TokenStream) -> TokenStream {
         }
     };
 
+    for (direction, serde_ast) in [
+        (Direction::Output, &serde_output),
+        (Direction::Input, &serde_input),
+    ] {
+        let name = direction.member_name(&serde_ast.attrs, input.remote.as_ref());
+        type_name::check(&name, parsed.ident.span());
+    }
+
+    let validate = impl_validate::expand(&input, &serde_input);
+
     let expanded = match input.data.clone() {
-        Data::Enum(e) => impl_enum::expand(input, &e, container, docs),
-        Data::Struct(e) => impl_struct::expand(input, e, container, docs),
+        Data::Enum(e) => impl_enum::expand(input, &e, serde_output, serde_input, docs),
+        Data::Struct(e) => impl_struct::expand(input, e, serde_output, serde_input, docs),
     };
-    expanded.into()
+
+    // the generated code refers to the type, its fields and its variants, which may be deprecated
+    quote::quote! {
+        #[allow(deprecated)]
+        const _: () = {
+            #expanded
+            #validate
+        };
+    }
+    .into()
+}
+
+fn parse_serde_container(
+    parsed: &syn::DeriveInput,
+    direction: Direction,
+) -> serde_derive_internals::ast::Container<'_> {
+    let cx = serde_derive_internals::Ctxt::new();
+
+    let container =
+        serde_derive_internals::ast::Container::from_ast(&cx, parsed, direction.derive()).unwrap();
+
+    cx.check().unwrap();
+    container
 }
 
 #[proc_macro_error]
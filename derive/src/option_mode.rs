@@ -0,0 +1,126 @@
+use crate::{args, direction::Direction, get_zod};
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::{quote, ToTokens};
+use serde_derive_internals::attr;
+use syn::spanned::Spanned;
+
+/// `#[zod(option = "nullable" | "optional" | "nullish")]`
+#[derive(FromMeta, Clone, Copy)]
+pub enum OptionMode {
+    #[darling(rename = "nullable")]
+    Nullable,
+    #[darling(rename = "optional")]
+    Optional,
+    #[darling(rename = "nullish")]
+    Nullish,
+}
+
+impl ToTokens for OptionMode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let zod = get_zod();
+        let mode = match self {
+            Self::Nullable => quote!(Nullable),
+            Self::Optional => quote!(Optional),
+            Self::Nullish => quote!(Nullish),
+        };
+
+        tokens.extend(quote!(#zod::ast::OptionMode::#mode))
+    }
+}
+
+/// The modifiers of a field which may be missing or contain `None`.
+///
+/// An output field is optional if it is skipped by `skip_serializing_if`, an input field if it has
+/// a default. `None` is represented as `null` unless the output field is skipped with
+/// `Option::is_none` or the container overrides the mode. Named input fields are nullish since
+/// serde deserializes a missing `Option` as `None`.
+///
+/// With `#[zod(default)]` the schema of an input field fills in the serde default instead of being
+/// optional, the typescript type stays optional.
+pub struct FieldModifiers {
+    option: Option<OptionMode>,
+    optional: bool,
+    /// an expression evaluating to the default value of the field
+    default: Option<TokenStream>,
+}
+
+impl FieldModifiers {
+    pub fn new(
+        direction: Direction,
+        container_mode: Option<OptionMode>,
+        field: &args::Field,
+        attrs: &attr::Field,
+    ) -> Self {
+        let default = field.default.is_present().then(|| {
+            let ty = &field.ty;
+            match attrs.default() {
+                attr::Default::None => abort!(
+                    ty.span(),
+                    "zod: `default` requires `#[serde(default)]` or `#[serde(default = \"..\")]`"
+                ),
+                attr::Default::Default => quote!(<#ty as ::std::default::Default>::default()),
+                attr::Default::Path(path) => quote!(#path()),
+            }
+        });
+
+        match direction {
+            Direction::Output => {
+                let skip_if = attrs.skip_serializing_if();
+
+                let skips_none = skip_if
+                    .and_then(|path| path.path.segments.last())
+                    .map(|segment| segment.ident == "is_none")
+                    .unwrap_or_default();
+
+                Self {
+                    option: container_mode.or(skips_none.then_some(OptionMode::Optional)),
+                    optional: skip_if.is_some(),
+                    default: None,
+                }
+            }
+            // serde fills in `None` for missing `Option` fields unless a custom function
+            // deserializes them, tuple elements have to be present
+            Direction::Input => {
+                let missing_is_none = field.ident.is_some() && attrs.deserialize_with().is_none();
+
+                Self {
+                    option: container_mode.or(missing_is_none.then_some(OptionMode::Nullish)),
+                    optional: !attrs.default().is_none(),
+                    default,
+                }
+            }
+        }
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    /// the method calls to apply to a `ZodSchema`, `TsType` or `TsField`
+    pub fn expand(&self) -> TokenStream {
+        let option = self.option.map(|mode| quote!(.with_option_mode(#mode)));
+        let optional = self.optional.then(|| quote!(.optional()));
+
+        quote!(#option #optional)
+    }
+
+    /// the method calls to apply to a `ZodSchema`, a default replaces `.optional()`
+    pub fn expand_schema(&self) -> TokenStream {
+        let Some(default) = &self.default else {
+            return self.expand();
+        };
+
+        let zod = get_zod();
+        let option = self.option.map(|mode| quote!(.with_option_mode(#mode)));
+
+        quote! {
+            #option
+            .with_default(
+                #zod::__private::serde_json::to_string(&#default)
+                    .expect("zod: the default value must serialize to JSON")
+            )
+        }
+    }
+}
@@ -3,6 +3,8 @@ use proc_macro_error::{abort, abort_call_site};
 use quote::format_ident;
 use syn::{parse_quote, Ident, ImplItem, ImplItemMethod, ItemImpl, Type};
 
+use crate::docs::RustDocs;
+
 #[derive(FromDeriveInput)]
 pub struct BackendInput {
     pub ident: syn::Ident,
@@ -48,6 +50,7 @@ pub struct RpcItem {
     pub arg_types: Vec<RpcArg>,
     pub kind: RpcItemKind,
     pub output: Box<Type>,
+    pub docs: RustDocs,
 }
 
 pub struct RpcArg {
@@ -57,6 +60,7 @@ pub struct RpcArg {
 
 impl RpcItem {
     pub fn from_ast(ast: ImplItemMethod) -> Self {
+        let docs = RustDocs::of(&ast.attrs);
         let sig = ast.sig;
         let ident = sig.ident;
         let is_async = sig.asyncness.is_some();
@@ -133,6 +137,7 @@ impl RpcItem {
             arg_types,
             kind,
             output,
+            docs,
         }
     }
 }
@@ -23,6 +23,7 @@ pub fn expand(input: RpcInput) -> TokenStream {
 
     quote_spanned! {
         ident.span() =>
+        #[allow(deprecated)]
         const _: () = {
             impl #__private::codegen::RpcNamespace for #ident {
                 type Req = #req_ident;
@@ -62,6 +63,10 @@ pub fn expand_inventory_submit(ns_ident: &Ident, item: &args::RpcItem) -> TokenS
     let __private = get_private();
     let zod = get_zod();
     let name = item.ident.to_string();
+    let docs = match item.docs.jsdoc() {
+        Some(docs) => quote!(::std::option::Option::Some(#docs)),
+        None => quote!(::std::option::Option::None),
+    };
 
     let args = item
         .arg_types
@@ -84,7 +89,8 @@ pub fn expand_inventory_submit(ns_ident: &Ident, item: &args::RpcItem) -> TokenS
                     args: &|| vec![
                         #(#args),*
                     ],
-                    res: &|| <#t as #zod::ZodType>::type_def().to_string(),
+                    res: &|| <#t as #zod::ZodType>::type_def().into(),
+                    docs: #docs,
                 });
 
             }
@@ -102,12 +108,13 @@ pub fn expand_inventory_submit(ns_ident: &Ident, item: &args::RpcItem) -> TokenS
                         #(#args),*
                     ],
                     res: &|| {
-                        fn extract_stream_item<S>(_: impl Fn(&mut #ns_ident, #(#arg_types),*) -> S) -> String where S: #__private::futures::Stream, S::Item: #zod::ZodType {
-                            <S::Item as #zod::ZodType>::type_def().to_string()
+                        fn extract_stream_item<S>(_: impl Fn(&mut #ns_ident, #(#arg_types),*) -> S) -> #zod::ast::TsType where S: #__private::futures::Stream, S::Item: #zod::ZodType {
+                            <S::Item as #zod::ZodType>::type_def().into()
                         }
 
                         extract_stream_item(#ns_ident :: #ident)
-                    }
+                    },
+                    docs: #docs,
                 });
             }
         }
@@ -119,7 +126,8 @@ pub fn expand_inventory_submit(ns_ident: &Ident, item: &args::RpcItem) -> TokenS
                     args: &|| vec![
                         #(#args),*
                     ],
-                    res: &|| <<#t as #zod::__private::futures::Stream>::Item as #zod::ZodType>::type_def().to_string(),
+                    res: &|| <<#t as #zod::__private::futures::Stream>::Item as #zod::ZodType>::type_def().into(),
+                    docs: #docs,
                 });
             }
         }
@@ -151,13 +159,72 @@ pub fn expand_req_variant_impl(input: &args::RpcItem) -> TokenStream {
         RpcItemKind::Stream => expand_req_variant_impl_stream(ident, expanded_args),
     };
 
+    let validation = expand_args_validation(input);
+    let deprecation = expand_deprecation_warning(input);
+
     quote! {
         Self::#ident { args } => {
+            #deprecation
+            #validation
             #inner
         }
     }
 }
 
+/// Calls of `#[deprecated]` methods are logged with the `zod::deprecated` target, which allows to
+/// silence them with a filter like `zod::deprecated=off`.
+fn expand_deprecation_warning(input: &args::RpcItem) -> TokenStream {
+    if !input.docs.is_deprecated() {
+        return TokenStream::new();
+    }
+
+    let __private = get_private();
+    let name = input.ident.to_string();
+
+    quote! {
+        #__private::tracing::warn!(
+            target: "zod::deprecated",
+            method = #name,
+            "called a deprecated rpc method"
+        );
+    }
+}
+
+/// Reject arguments which violate their refinements before calling the method. Arguments whose
+/// type does not implement `Validate` are always accepted.
+fn expand_args_validation(input: &args::RpcItem) -> TokenStream {
+    let __private = get_private();
+    let zod = get_zod();
+
+    let checks = input
+        .arg_types
+        .iter()
+        .enumerate()
+        .map(|(i, RpcArg { name, .. })| {
+            let index = syn::Index::from(i);
+            quote! {
+                (&#zod::validation::__private::Probe(&args.#index))
+                    .validate_at(&[#zod::validation::PathSegment::from(#name)], &mut errors);
+            }
+        });
+
+    quote! {
+        {
+            use #zod::validation::__private::{ViaNoop as _, ViaValidate as _};
+
+            let mut errors = #zod::validation::ValidationErrors::default();
+            #(#checks)*
+
+            if let ::std::result::Result::Err(err) = errors.into_result() {
+                sender
+                    .unbounded_send(#__private::Response::error(id, err))
+                    .unwrap();
+                return None;
+            }
+        }
+    }
+}
+
 pub fn expand_req_variant_impl_method(
     ident: &Ident,
     expanded_args: impl Iterator<Item = TokenStream>,
@@ -0,0 +1,88 @@
+use crate::{args::Field, direction::Direction, get_zod};
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::spanned::Spanned;
+
+/// A schema which replaces the one of the field type. This is mostly needed for fields which are
+/// (de)serialized with `#[serde(with = "..")]`.
+pub enum SchemaOverride<'a> {
+    /// `#[zod(as = "Type")]` or `#[zod(with = "Mirror")]`, the field is represented like `Type`
+    As(&'a syn::Type),
+    /// `#[zod(schema = "z.string()", ts = "string")]`, hand written zod and typescript
+    Raw {
+        schema: &'a str,
+        ts: Option<&'a str>,
+    },
+    /// `#[zod(schema_with = "path::to_fn", ts = "string")]`, a function returning the `ZodSchema`
+    With {
+        path: &'a syn::Path,
+        ts: Option<&'a str>,
+    },
+}
+
+impl<'a> SchemaOverride<'a> {
+    pub fn new(field: &'a Field) -> Option<Self> {
+        let ts = field.ts.as_deref();
+
+        let conflict = || -> ! {
+            abort!(
+                field.ty.span(),
+                "zod: `as`, `with`, `schema` and `schema_with` are mutually exclusive"
+            )
+        };
+
+        let as_type = match (&field.as_type, &field.with) {
+            (Some(_), Some(_)) => conflict(),
+            (as_type, with) => as_type.as_ref().or(with.as_ref()),
+        };
+
+        let value = match (as_type, &field.schema, &field.schema_with) {
+            (_, None, None) if ts.is_some() => abort!(
+                field.ty.span(),
+                "zod: `ts` requires `schema` or `schema_with`"
+            ),
+            (None, None, None) => return None,
+            (Some(ty), None, None) => Self::As(ty),
+            (None, Some(schema), None) => Self::Raw { schema, ts },
+            (None, None, Some(path)) => Self::With { path, ts },
+            _ => conflict(),
+        };
+
+        Some(value)
+    }
+
+    /// the `ZodSchema` of the field value
+    pub fn expand_schema(&self, direction: Direction) -> TokenStream {
+        let zod = get_zod();
+
+        match self {
+            Self::As(ty) => {
+                let inline_schema = direction.inline_schema();
+                quote!(<#ty as #zod::ZodType>::#inline_schema())
+            }
+            Self::Raw { schema, .. } => {
+                quote!(#zod::ast::ZodSchema::Raw(String::from(#schema)))
+            }
+            Self::With { path, .. } => quote!(#path()),
+        }
+    }
+
+    /// the `TsType` of the field value, hand written schemas without `ts` are `unknown`
+    pub fn expand_type(&self, direction: Direction) -> TokenStream {
+        let zod = get_zod();
+
+        match self {
+            Self::As(ty) => {
+                let inline = direction.inline();
+                quote!(#zod::ast::TsType::from(<#ty as #zod::ZodType>::#inline()))
+            }
+            Self::Raw { ts: Some(ts), .. } | Self::With { ts: Some(ts), .. } => {
+                quote!(#zod::ast::TsType::Raw(String::from(#ts)))
+            }
+            Self::Raw { ts: None, .. } | Self::With { ts: None, .. } => {
+                quote!(#zod::ast::TsType::Unknown)
+            }
+        }
+    }
+}
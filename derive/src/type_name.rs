@@ -0,0 +1,112 @@
+use proc_macro2::Span;
+use proc_macro_error::abort;
+
+/// Words typescript reserves in a module, they can't name a `const` or a `type`
+const RESERVED: &[&str] = &[
+    // keywords
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    // reserved in strict mode, which modules always are
+    "arguments",
+    "await",
+    "eval",
+    "implements",
+    "interface",
+    "let",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "static",
+    "yield",
+    // predefined types
+    "any",
+    "bigint",
+    "boolean",
+    "never",
+    "number",
+    "object",
+    "string",
+    "symbol",
+    "undefined",
+    "unknown",
+];
+
+/// Names the generated code refers to, a namespace member with one of them would shadow it: an
+/// `export interface Record` turns every `Record<K, V>` of its namespace into a reference to it.
+const SHADOWED: &[&str] = &[
+    // typescript and javascript globals
+    "Array",
+    "Map",
+    "Object",
+    "Partial",
+    "Promise",
+    "Record",
+    // the zod import and the rpc client
+    "z",
+    "Store",
+    "request",
+    "subscribe",
+];
+
+/// Namespaces and their members are emitted as `export namespace Name`, `export const Name` and
+/// `export type Name`. Names which are not identifiers, reserved by typescript or used by the
+/// generated code, e.g. from `#[serde(rename = "..")]`, would produce invalid code and are
+/// rejected.
+pub fn check(name: &str, span: Span) {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+
+    if !is_identifier {
+        abort!(span, "zod: `{}` is not a valid typescript identifier", name)
+    }
+
+    if RESERVED.contains(&name) {
+        abort!(span, "zod: `{}` is a reserved word in typescript", name)
+    }
+
+    if SHADOWED.contains(&name) {
+        abort!(
+            span,
+            "zod: `{}` would shadow a name the generated typescript refers to",
+            name
+        )
+    }
+}
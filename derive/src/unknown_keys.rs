@@ -0,0 +1,44 @@
+use crate::get_zod;
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use serde_derive_internals::attr;
+
+/// `#[zod(unknown_keys = "strip" | "passthrough" | "strict")]`
+#[derive(FromMeta, Clone, Copy)]
+pub enum UnknownKeys {
+    #[darling(rename = "strip")]
+    Strip,
+    #[darling(rename = "passthrough")]
+    Passthrough,
+    #[darling(rename = "strict")]
+    Strict,
+}
+
+impl UnknownKeys {
+    /// The policy of an object. An explicit policy wins over `#[serde(deny_unknown_fields)]`
+    /// which makes the object strict.
+    pub fn resolve(explicit: Option<Self>, serde_attrs: &attr::Container) -> Option<Self> {
+        explicit.or_else(|| serde_attrs.deny_unknown_fields().then_some(Self::Strict))
+    }
+
+    /// the method call to apply to a `ZodObject`
+    pub fn expand(policy: Option<Self>) -> TokenStream {
+        policy
+            .map(|policy| quote!(.with_unknown_keys(#policy)))
+            .unwrap_or_default()
+    }
+}
+
+impl ToTokens for UnknownKeys {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let zod = get_zod();
+        let policy = match self {
+            Self::Strip => quote!(Strip),
+            Self::Passthrough => quote!(Passthrough),
+            Self::Strict => quote!(Strict),
+        };
+
+        tokens.extend(quote!(#zod::ast::UnknownKeys::#policy))
+    }
+}
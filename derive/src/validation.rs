@@ -0,0 +1,246 @@
+use crate::{args::Field, get_zod};
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{spanned::Spanned, GenericArgument, Lit, PathArguments, Type};
+
+/// The refinements declared on a field, eg. `#[zod(min_length = 3, email)]`. They are appended to
+/// the schema of the field value as `.refine(...)` calls.
+pub fn expand_refinements(field: &Field) -> TokenStream {
+    let refinements = refinements(field);
+    quote!(#(.refine(#refinements))*)
+}
+
+/// the `zod::ast::Refinement` values declared on a field
+pub fn refinements(field: &Field) -> Vec<TokenStream> {
+    let zod = get_zod();
+    let mut refinements = Vec::new();
+
+    if let (Some(min), Some(max)) = (field.min_length, field.max_length) {
+        if min > max {
+            abort!(
+                field.ty.span(),
+                "zod: `min_length` must not be greater than `max_length`"
+            )
+        }
+    }
+
+    if field.email.is_present() && field.url.is_present() {
+        abort!(
+            field.ty.span(),
+            "zod: `email` and `url` are mutually exclusive"
+        )
+    }
+
+    check_value_kind(field);
+
+    if let Some(n) = field.min_length {
+        refinements.push(quote!(MinLength(#n)));
+    }
+
+    if let Some(n) = field.max_length {
+        refinements.push(quote!(MaxLength(#n)));
+    }
+
+    if let Some(lit) = &field.min {
+        let n = expand_numeric(lit);
+        refinements.push(quote!(Min(#n)));
+    }
+
+    if let Some(lit) = &field.max {
+        let n = expand_numeric(lit);
+        refinements.push(quote!(Max(#n)));
+    }
+
+    if let Some(pattern) = &field.regex {
+        check_regex(pattern);
+        refinements.push(quote!(Regex(String::from(#pattern))));
+    }
+
+    if field.email.is_present() {
+        refinements.push(quote!(Email));
+    }
+
+    if field.url.is_present() {
+        refinements.push(quote!(Url));
+    }
+
+    if field.non_empty.is_present() {
+        refinements.push(quote!(NonEmpty));
+    }
+
+    refinements
+        .into_iter()
+        .map(|refinement| quote!(#zod::ast::Refinement::#refinement))
+        .collect()
+}
+
+/// What the value of a field is represented as. Refinements only apply to some of them.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    String,
+    Number,
+    Sequence,
+    Other,
+}
+
+impl ValueKind {
+    /// the kind of a field type, `Option` and smart pointers are looked through
+    fn of(ty: &Type) -> Self {
+        match ty {
+            Type::Reference(reference) => Self::of(&reference.elem),
+            Type::Paren(paren) => Self::of(&paren.elem),
+            Type::Group(group) => Self::of(&group.elem),
+            Type::Array(_) | Type::Slice(_) => Self::Sequence,
+            Type::Path(path) if path.qself.is_none() => {
+                let segment = match path.path.segments.last() {
+                    Some(segment) => segment,
+                    None => return Self::Other,
+                };
+
+                match segment.ident.to_string().as_str() {
+                    "String" | "str" => Self::String,
+                    "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32"
+                    | "i64" | "i128" | "isize" | "f32" | "f64" => Self::Number,
+                    "Vec" | "HashSet" | "BTreeSet" => Self::Sequence,
+                    "Option" | "Box" | "Arc" | "Rc" => match &segment.arguments {
+                        PathArguments::AngleBracketed(args) => match args.args.first() {
+                            Some(GenericArgument::Type(inner)) => Self::of(inner),
+                            _ => Self::Other,
+                        },
+                        _ => Self::Other,
+                    },
+                    _ => Self::Other,
+                }
+            }
+            _ => Self::Other,
+        }
+    }
+}
+
+/// abort if a refinement doesn't apply to the type of the field, eg. `email` on a number
+fn check_value_kind(field: &Field) {
+    // hand written schemas are not checked
+    if field.schema.is_some() || field.schema_with.is_some() {
+        return;
+    }
+
+    let ty = field.as_type.as_ref().unwrap_or(&field.ty);
+    let integer_mode = field.bigint.is_present() || field.as_string.is_present();
+
+    let kind = match ValueKind::of(ty) {
+        // represented as `bigint` or a string, but checked as a number in rust
+        ValueKind::Number if integer_mode => ValueKind::Other,
+        kind => kind,
+    };
+
+    let length = matches!(kind, ValueKind::String | ValueKind::Sequence);
+    let string = kind == ValueKind::String;
+    let number = kind == ValueKind::Number;
+    let a_number = if integer_mode {
+        "a number without `bigint` or `as_string`"
+    } else {
+        "a number"
+    };
+
+    let refinements = [
+        (
+            "min_length",
+            field.min_length.is_some(),
+            length,
+            "a string or a sequence",
+        ),
+        (
+            "max_length",
+            field.max_length.is_some(),
+            length,
+            "a string or a sequence",
+        ),
+        (
+            "non_empty",
+            field.non_empty.is_present(),
+            length,
+            "a string or a sequence",
+        ),
+        ("min", field.min.is_some(), number, a_number),
+        ("max", field.max.is_some(), number, a_number),
+        ("regex", field.regex.is_some(), string, "a string"),
+        ("email", field.email.is_present(), string, "a string"),
+        ("url", field.url.is_present(), string, "a string"),
+    ];
+
+    for (name, declared, applies, expected) in refinements {
+        if declared && !applies {
+            abort!(
+                ty.span(),
+                "zod: `{}` requires the field to be {}",
+                name,
+                expected
+            )
+        }
+    }
+}
+
+/// The backend checks patterns with the `regex` crate, which lacks some javascript features like
+/// lookarounds or backreferences. A pattern it can't compile would reject every value.
+fn check_regex(pattern: &syn::LitStr) {
+    if let Err(err) = regex::Regex::new(&js_regex(&pattern.value())) {
+        abort!(
+            pattern.span(),
+            "zod: `regex` is not supported by the rust regex crate: {}",
+            err
+        )
+    }
+}
+
+/// Translate the classes which only match ascii in javascript, eg. `\d`, into rust syntax. Mirrors
+/// `zod::validation::js_regex` which the backend compiles.
+fn js_regex(pattern: &str) -> String {
+    let mut out = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('d') => out.push_str("[0-9]"),
+            Some('D') => out.push_str("[^0-9]"),
+            Some('w') => out.push_str("[0-9A-Za-z_]"),
+            Some('W') => out.push_str("[^0-9A-Za-z_]"),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+
+    out
+}
+
+/// `min = 0`, `max = 1.5` or `min = "-10"` for negative bounds
+fn expand_numeric(lit: &Lit) -> TokenStream {
+    let zod = get_zod();
+
+    let parsed = match lit {
+        Lit::Int(int) => int.base10_parse::<i128>().ok().map(|n| quote!(Signed(#n))),
+        Lit::Float(float) => float.base10_parse::<f64>().ok().map(|n| quote!(Float(#n))),
+        Lit::Str(s) => {
+            let value = s.value();
+            value
+                .parse::<i128>()
+                .map(|n| quote!(Signed(#n)))
+                .or_else(|_| value.parse::<f64>().map(|n| quote!(Float(#n))))
+                .ok()
+        }
+        _ => None,
+    };
+
+    match parsed {
+        Some(n) => quote!(#zod::ast::Numeric::#n),
+        None => abort!(lit.span(), "zod: expected a number"),
+    }
+}
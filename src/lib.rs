@@ -50,7 +50,7 @@
 //! Deriving Zod implements the [ZodType](https://docs.rs/zod-core/ZodType) trait for you exposing a couple of methods to the
 //! typescript/schema representation of your rust types.
 //!
-//! Calling `MyStruct::schema()` will give you a string with a valid zod schema definition:
+//! Calling `MyStruct::schema()` will give you a `ZodSchema` which renders to a valid zod schema definition:
 //! ```ts
 //! z.object({ port: Rs.U16, data: Ns.MyData })
 //! ```
@@ -74,6 +74,102 @@
 //! { Hello: Rs.String } | { World: [Rs.Usize] }
 //! ```
 //!
+//! Enums with only unit variants become `z.enum(["A", "B"])` and the generated code exports their
+//! values as `const MyEnumEnum = { "A": "A", "B": "B" } as const`. With `#[zod(ts_enum)]` a
+//! typescript `enum MyEnumEnum` is exported instead and validated with `z.nativeEnum`.
+//! Enums serialized as their discriminant, eg. with `serde_repr`, opt in with `#[zod(repr)]` and
+//! become `z.union([z.literal(0), z.literal(1)])`. Their variant names are kept in `MyEnumEnum`.
+//!
+//! Structs with `#[serde(tag = "type")]` get a `type: z.literal("Name")` field, so they can be
+//! told apart in unions. Serde ignores the tag while deserializing and the input flavour accepts
+//! objects without it.
+//!
+//! Unit structs are serialized as `null` and become `z.null()`. Empty enums are uninhabited and
+//! become `z.never()`, eg. as the error type of a method which can't fail.
+//!
+//! Inputs of internally or adjacently tagged enums with a `#[serde(other)]` variant also accept
+//! objects with any other tag. `#[non_exhaustive]` enums may opt in with `#[zod(open)]` to accept
+//! unknown variants in their outputs, eg. `"A" | "B" | (string & {})`, so older clients keep
+//! working when variants are added.
+//!
+//! Serde may treat both directions differently, eg. `#[serde(default)]` only affects
+//! deserialization. Types whose input differs are therefore also exported with an `Input` suffix
+//! (`MyStructInput`) which describes the values rust deserializes, identical inputs refer to the
+//! output instead. The input flavour is available through `input_schema` and `input_type_def` and
+//! is used for the arguments of rpc methods. Code generation panics if a type named like an input
+//! flavour, eg. `MyStructInput`, is in the same namespace.
+//!
+//! Fields can be refined with `#[zod(min_length = 1, max_length = 64, regex = "^[a-z]+$")]`,
+//! `#[zod(email)]`, `#[zod(url)]`, `#[zod(min = 0, max = 100)]` and `#[zod(non_empty)]`. Negative
+//! bounds are written as strings, eg. `#[zod(min = "-10")]`. Lengths apply to strings and
+//! sequences, `min` and `max` to numbers and the other refinements to strings, any other
+//! combination is rejected. Patterns are checked by the `regex` crate in rust and must not use
+//! lookarounds or backreferences. With `#[zod(validate)]` the type also
+//! implements [validation::Validate] which enforces the same rules in rust, `email` uses the
+//! pattern of zod and `url` the WHATWG parser of the `URL` constructor. Type parameters of a
+//! validated type are bound by `Validate`. Rpc arguments are
+//! validated before the method is called and rejected with a `ValidationError`.
+//!
+//! [validate::assert_conforms] serializes a value with serde and checks it against the generated
+//! schema in rust. This catches mismatches between serde and the schema without running node.
+//! With the `proptest` feature, [arbitrary] generates JSON values from the schema and
+//! [arbitrary::assert_arbitrary_conforms] checks that arbitrary rust values serialize into it.
+//!
+//! `u64`, `i64`, `u128` and `i128` exceed `Number.MAX_SAFE_INTEGER` and lose precision as
+//! javascript numbers. Fields annotated with `#[zod(bigint)]` are typed as `bigint` and
+//! `#[zod(as_string)]` fields as decimal strings. Both require the field to be sent as a string
+//! with `#[serde(with = "zod::bigint::as_string")]`. `CodegenOptions::integer_mode` types all
+//! remaining wide integers as `bigint` which are parsed from the JSON numbers serde sends,
+//! precision above `Number.MAX_SAFE_INTEGER` is only kept for fields using the helper.
+//!
+//! Fields which serde (de)serializes with a custom function, eg. `#[serde(with = "..")]`, keep the
+//! schema of their type unless it is overridden. `#[zod(as = "u64")]` represents the field like
+//! another type, `#[zod(schema = "z.string().datetime()", ts = "string")]` emits hand written code
+//! and `#[zod(schema_with = "path::to_fn", ts = "string")]` calls a function returning the
+//! `ZodSchema`. Without `ts` the typescript type is `unknown`.
+//!
+//! Input fields with `#[serde(default)]` are `.optional()`, so a parsed value may lack fields the
+//! server fills in. With `#[zod(default)]` the rust default is serialized when the code is generated
+//! and emitted as `.default(<json>)`, which makes the field non-optional in `z.output`.
+//!
+//! Newtypes like `struct UserId(String)` are represented like the wrapped type, so typescript
+//! accepts any string as a `UserId`. With `#[zod(brand)]` or `#[zod(brand = "Name")]` they become
+//! nominal: `z.string().brand<"UserId">()` with the type `string & { __brand: "UserId" }`. The
+//! generated code exports a `parseUserId` function which creates values of the branded type.
+//!
+//! Foreign types can't implement `ZodType` because of the orphan rule. Like with serde, a mirror
+//! definition annotated with `#[zod(remote = "other_crate::Type")]` is registered under the name of
+//! the foreign type and fields use it with `#[zod(with = "Mirror")]`.
+//!
+//! Objects strip unknown keys like zod does by default. Containers with
+//! `#[serde(deny_unknown_fields)]` become `.strict()` and `#[zod(unknown_keys = "strip" |
+//! "passthrough" | "strict")]` overrides the policy of a struct, an enum or a single struct variant.
+//! A flattened map collects all remaining keys and becomes the `.catchall(...)` of the object.
+//!
+//! Structs with `#[serde(flatten)]` fields are declared as `interface Foo extends Ns.Bar { ... }`
+//! as long as every flattened type is an interface itself. Otherwise, e.g. for flattened enums or
+//! maps, they become a type alias of the intersection. A flattened `Option<Bar>` adds either all
+//! fields of `Bar` or none, its type is `Ns.Bar | {}` and the object is intersected with
+//! `z.union([Bar, z.object({})])`.
+//!
+//! Object keys which are not plain identifiers, e.g. from `#[serde(rename_all = "kebab-case")]`,
+//! are quoted and string literals are escaped. Types and namespaces must be named by typescript
+//! identifiers which are not reserved words, `#[serde(rename = "class")]` on a type is rejected.
+//! Names the generated code refers to, like `Record`, `Promise` or `z`, are rejected as well.
+//!
+//! Aliases from `#[serde(alias = "..")]` are accepted by the input flavour. Objects with aliased
+//! fields rename the keys with `z.preprocess` before parsing them and aliased variants accept
+//! every alias as their name or tag.
+//!
+//! Doc comments of fields and variants are emitted as JSDoc in the type definitions and as
+//! `.describe("...")` in the schemas, so editors show them on hover and tools like
+//! `zod-to-json-schema` pick them up.
+//!
+//! Types, fields, variants and rpc methods marked `#[deprecated(note = "...")]` carry a
+//! `@deprecated` tag in their JSDoc, which editors render as a strikethrough. Calls of deprecated
+//! rpc methods are logged as warnings with the `zod::deprecated` target, a filter like
+//! `zod::deprecated=off` silences them.
+//!
 //! ## TODO
 //! - [x] Codegen for struct style enums
 //! - [x] implement all missing serde attrs where possible. see: [ts-rs](https://docs.rs/ts-rs/latest/ts_rs/)
@@ -87,6 +183,7 @@
 //!         - [x] untagged
 //!    - [x] skip
 //!    - [x] skip_deserializing
+//!    - [x] skip_serializing_if
 //!    - [x] default
 //!    - [x] transparent structs
 //!    - [x] flatten
@@ -100,7 +197,7 @@
 //! - [x] write detailed intro
 //! - [x] write rust-docs
 //!
-//! - [ ] Consider to allow the use of generics otherwise force implementors to not have generics
+//! - [x] Consider to allow the use of generics otherwise force implementors to not have generics
 //! - [ ] RPC ui tests
 //! - [ ] improve diagnostics on rpc (eg. correct spans, better compile time errors)
 //! - [ ] improve macro hygiene
@@ -111,9 +208,6 @@
 //! - [ ] consider making Result/Option "smart" classes with methods like `unwrap`, `map`, `is_ok`, `is_none` etc.
 //! - [ ] make rpc a feature or consider splitting the crates entirely
 //!
-//! ## Consideration?
-//! - flattening a `std::collections::HashMap` onto a struct. This works in serde but not in zod because we represent the `HashMap` as a `z.map([..])` which represents a `Map` in ts/js.
-//!
 //! ## Contributing
 //! Contribution is more than welcome. This crate is extensively tested but there are a lot of edge-cases. If you find anything that is not working but should, please let meknow.
 //!
@@ -127,6 +127,9 @@ type Store<T> = {
 };
 
 const CONNECTION = websocketStore(WS_ADDR);
+const U64_MAX = 18446744073709551615n;
+const BIGINT_MARKER = "__zod_bigint__";
+const BIGINT_PATTERN = new RegExp(`"${BIGINT_MARKER}(-?\\d+)"`, "g");
 let req_id = 0;
 
 function execute({
@@ -140,7 +143,12 @@ function execute({
   method: string;
   args: any[];
 }): string {
-  return JSON.stringify({ exec: { id: req_id, method, namespace, args } });
+  // bigints are not supported by JSON. Those in the range of a 64 bit integer are sent as plain
+  // numbers which serde deserializes without losing precision, wider ones as decimal strings.
+  return JSON.stringify({ exec: { id: req_id, method, namespace, args } }, (_, value) => {
+    if (typeof value !== "bigint") return value;
+    return -U64_MAX <= value && value <= U64_MAX ? `${BIGINT_MARKER}${value}` : value.toString();
+  }).replace(BIGINT_PATTERN, "$1");
 }
 
 export function subscribe<T>(
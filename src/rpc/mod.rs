@@ -3,7 +3,9 @@
 pub mod clients;
 pub mod servers;
 
-pub use zod_core::rpc::{error::Error, server::Backend, server::SubscriberMap, Request, Response};
+pub use zod_core::rpc::{
+    error::Error, server::Backend, server::CodegenOptions, server::SubscriberMap, Request, Response,
+};
 pub use zod_derive::{namespace, Backend};
 
 #[async_trait::async_trait]
@@ -0,0 +1,112 @@
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use zod::validate::validate_schema;
+use zod::{Namespace, Zod, ZodType};
+
+#[derive(Namespace)]
+#[namespace(name = "Ns")]
+pub struct Ns {}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct User {
+    #[serde(alias = "login", alias = "username")]
+    name: String,
+    admin: bool,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+enum Color {
+    Red,
+    #[serde(alias = "Grey")]
+    Gray,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(tag = "type")]
+enum Event {
+    #[serde(alias = "Removed")]
+    Deleted { id: String },
+    Created {
+        #[serde(alias = "key")]
+        id: String,
+    },
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+enum Shape {
+    #[serde(alias = "Circle")]
+    Round(f32),
+    Square(f32),
+}
+
+/// the input schema accepts every value serde deserializes
+fn accepts<T>(value: serde_json::Value) -> bool
+where
+    T: ZodType + for<'de> Deserialize<'de>,
+{
+    let valid = validate_schema(&T::input_schema(), &value).is_ok();
+    assert_eq!(valid, serde_json::from_value::<T>(value).is_ok());
+    valid
+}
+
+#[test]
+fn field_aliases() {
+    let object = format!(
+        "z.object({{ name: {}, admin: z.boolean() }})",
+        String::schema()
+    );
+
+    assert_eq!(User::schema(), object);
+    assert_eq!(
+        User::input_schema(),
+        format!("z.preprocess((value) => value !== null && typeof value === \"object\" && !Array.isArray(value) ? Object.fromEntries(Object.entries(value).map(([key, v]) => [new Map([[\"login\", \"name\"], [\"username\", \"name\"]]).get(key) ?? key, v])) : value, {object})")
+    );
+    assert_eq!(User::input_type_def(), User::type_def());
+
+    assert!(accepts::<User>(json!({ "name": "a", "admin": true })));
+    assert!(accepts::<User>(json!({ "login": "a", "admin": true })));
+    assert!(!accepts::<User>(json!({ "nickname": "a", "admin": true })));
+}
+
+#[test]
+fn unit_variant_aliases() {
+    assert_eq!(Color::schema(), "z.enum([\"Red\", \"Gray\"])");
+    assert_eq!(
+        Color::input_schema(),
+        "z.enum([\"Red\", \"Gray\", \"Grey\"])"
+    );
+    assert_eq!(Color::input_type_def(), "\"Red\" | \"Gray\" | \"Grey\"");
+
+    assert!(accepts::<Color>(json!("Grey")));
+    assert!(!accepts::<Color>(json!("Blue")));
+}
+
+#[test]
+fn tagged_variant_aliases() {
+    assert_eq!(
+        Event::input_type_def(),
+        "{ type: \"Deleted\" | \"Removed\", id: string } | { type: \"Created\", id: string }"
+    );
+    assert!(Event::input_schema()
+        .to_string()
+        .starts_with("z.union([z.object({ type: z.enum([\"Deleted\", \"Removed\"]), id: "));
+    assert!(Event::schema()
+        .to_string()
+        .starts_with("z.discriminatedUnion(\"type\", ["));
+
+    assert!(accepts::<Event>(json!({ "type": "Removed", "id": "a" })));
+    assert!(accepts::<Event>(json!({ "type": "Created", "key": "a" })));
+    assert!(!accepts::<Event>(json!({ "type": "Added", "id": "a" })));
+}
+
+#[test]
+fn external_variant_aliases() {
+    assert!(accepts::<Shape>(json!({ "Circle": 1.0 })));
+    assert!(accepts::<Shape>(json!({ "Round": 1.0 })));
+    assert!(!accepts::<Shape>(json!({ "Oval": 1.0 })));
+}
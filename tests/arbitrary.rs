@@ -0,0 +1,124 @@
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use zod::arbitrary::{assert_arbitrary_conforms, assert_arbitrary_input_deserializes};
+use zod::{Namespace, Zod};
+
+#[derive(Namespace)]
+struct Ns {}
+
+#[derive(Zod, Serialize, Deserialize, Arbitrary, Debug)]
+#[zod(namespace = "Ns")]
+struct User {
+    #[zod(min_length = 1, max_length = 8)]
+    #[proptest(regex = "[a-z]{1,8}")]
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nick: Option<String>,
+    #[serde(default)]
+    age: u8,
+    scores: BTreeMap<String, Vec<i32>>,
+    #[serde(flatten)]
+    meta: Meta,
+}
+
+#[derive(Zod, Serialize, Deserialize, Arbitrary, Debug)]
+#[zod(namespace = "Ns")]
+struct Meta {
+    created: u64,
+    #[zod(email)]
+    #[proptest(value = "String::from(\"a@b.io\")")]
+    contact: String,
+}
+
+#[derive(Zod, Serialize, Deserialize, Arbitrary, Debug)]
+#[zod(namespace = "Ns")]
+struct Tuple(String, Option<bool>, f64);
+
+#[derive(Zod, Serialize, Deserialize, Arbitrary, Debug)]
+#[zod(namespace = "Ns")]
+enum External {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, String),
+    Struct { value: bool },
+}
+
+#[derive(Zod, Serialize, Deserialize, Arbitrary, Debug)]
+#[zod(namespace = "Ns")]
+#[serde(tag = "type")]
+enum Internal {
+    Unit,
+    Newtype(Tuple2),
+    Struct { value: bool },
+}
+
+#[derive(Zod, Serialize, Deserialize, Arbitrary, Debug)]
+#[zod(namespace = "Ns")]
+struct Tuple2 {
+    a: i16,
+}
+
+#[derive(Zod, Serialize, Deserialize, Arbitrary, Debug)]
+#[zod(namespace = "Ns")]
+#[serde(tag = "type", content = "content")]
+enum Adjacent {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, String),
+    Struct { value: bool },
+}
+
+#[derive(Zod, Serialize, Deserialize, Arbitrary, Debug)]
+#[zod(namespace = "Ns")]
+#[serde(untagged)]
+enum Untagged {
+    Newtype(u8),
+    Struct { value: bool },
+}
+
+#[derive(Zod, Serialize, Deserialize, Arbitrary, Debug)]
+#[zod(namespace = "Ns")]
+struct Flattened {
+    id: u32,
+    #[serde(flatten)]
+    inner: Internal,
+}
+
+#[derive(Zod, Serialize, Deserialize, Debug)]
+#[zod(namespace = "Ns")]
+struct Recursive {
+    children: Vec<Recursive>,
+    next: Option<Box<Recursive>>,
+}
+
+#[test]
+fn structs() {
+    assert_arbitrary_conforms::<User>();
+    assert_arbitrary_input_deserializes::<User>();
+    assert_arbitrary_conforms::<Tuple>();
+    assert_arbitrary_input_deserializes::<Tuple>();
+}
+
+#[test]
+fn enums() {
+    assert_arbitrary_conforms::<External>();
+    assert_arbitrary_input_deserializes::<External>();
+    assert_arbitrary_conforms::<Internal>();
+    assert_arbitrary_input_deserializes::<Internal>();
+    assert_arbitrary_conforms::<Adjacent>();
+    assert_arbitrary_input_deserializes::<Adjacent>();
+    assert_arbitrary_conforms::<Untagged>();
+    assert_arbitrary_input_deserializes::<Untagged>();
+}
+
+#[test]
+fn flattened_enums() {
+    assert_arbitrary_conforms::<Flattened>();
+    assert_arbitrary_input_deserializes::<Flattened>();
+}
+
+#[test]
+fn recursive_types() {
+    assert_arbitrary_input_deserializes::<Recursive>();
+}
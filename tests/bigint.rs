@@ -0,0 +1,188 @@
+use pretty_assertions::assert_eq;
+use serde::Deserialize;
+use zod::ast::IntegerMode;
+use zod::rpc::{self, clients::WebsocketClient, Backend, CodegenOptions};
+use zod::validate::{assert_conforms, validate_schema};
+use zod::ZodType;
+
+mod test_utils;
+use test_utils::*;
+
+/// the value conforms to the output schema and its JSON is accepted by the input schema and
+/// deserialized back into the same value
+fn round_trip<T>(value: T)
+where
+    T: ZodType + serde::Serialize + for<'de> Deserialize<'de> + PartialEq + std::fmt::Debug,
+{
+    assert_conforms::<T>(&value);
+
+    let json = serde_json::to_value(&value).unwrap();
+    if let Err(errors) = validate_schema(&T::input_schema(), &json) {
+        panic!("{json} does not conform to the input schema: {errors}");
+    }
+    assert_eq!(serde_json::from_value::<T>(json).unwrap(), value);
+}
+
+#[test]
+fn numbers_by_default() {
+    test_case! {
+        struct Test {
+            value: u64,
+        }
+    }
+
+    assert_eq!(
+        Test::schema(),
+        object!(value: "z.number().finite().int().nonnegative().lte(18446744073709551615)")
+    );
+    assert_eq!(Test::type_def(), "{ value: number }");
+}
+
+#[test]
+fn bigint_field() {
+    test_case! {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Test {
+            #[zod(bigint)]
+            #[serde(with = "zod::bigint::as_string")]
+            value: u64,
+        }
+    }
+
+    assert_eq!(
+        serde_json::to_value(Test { value: u64::MAX }).unwrap(),
+        serde_json::json!({ "value": "18446744073709551615" })
+    );
+    round_trip(Test { value: u64::MAX });
+    round_trip(Test { value: 0 });
+
+    assert_eq!(
+        Test::schema(),
+        object!(value: "z.coerce.bigint().gte(0n).lte(18446744073709551615n)")
+    );
+    assert_eq!(Test::type_def(), "{ value: bigint }");
+    assert_eq!(Test::input_type_def(), "{ value: bigint }");
+}
+
+#[test]
+fn string_field() {
+    test_case! {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Test {
+            #[zod(as_string)]
+            #[serde(with = "zod::bigint::as_string")]
+            value: i128,
+        }
+    }
+
+    assert_eq!(
+        Test::schema(),
+        object!(value: r"z.string().regex(/^-?\d+$/)")
+    );
+    assert_eq!(Test::type_def(), "{ value: string }");
+
+    round_trip(Test { value: i128::MIN });
+    round_trip(Test { value: i128::MAX });
+}
+
+#[test]
+fn enum_fields() {
+    test_case! {
+        #[derive(Deserialize, PartialEq, Debug)]
+        enum Test {
+            A(
+                #[zod(bigint)]
+                #[serde(with = "zod::bigint::as_string")]
+                i64,
+            ),
+            B {
+                #[zod(as_string)]
+                #[serde(with = "zod::bigint::as_string")]
+                value: u128,
+            },
+        }
+    }
+
+    assert_eq!(
+        Test::schema(),
+        zod_union(&[
+            object!(A: "z.coerce.bigint().gte(-9223372036854775808n).lte(9223372036854775807n)"),
+            object!(B: object!(value: r"z.string().regex(/^\d+$/)")),
+        ])
+    );
+    assert_eq!(Test::type_def(), "{ A: bigint } | { B: { value: string } }");
+
+    round_trip(Test::A(i64::MIN));
+    round_trip(Test::B { value: u128::MAX });
+}
+
+mod rpc_args {
+    use super::*;
+
+    #[derive(zod::Namespace)]
+    pub struct Wide {}
+
+    #[derive(zod::Zod, serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    #[zod(namespace = "Wide")]
+    pub struct Balance {
+        #[zod(bigint)]
+        #[serde(with = "zod::bigint::as_string")]
+        exact: u64,
+        approximate: u64,
+    }
+
+    #[rpc::namespace]
+    impl Wide {
+        pub async fn total(&mut self, _balance: Balance, _limit: u64) -> i64 {
+            0
+        }
+    }
+
+    #[derive(Backend)]
+    struct MyBackend(Wide);
+
+    #[test]
+    fn unannotated_integers_are_numbers() {
+        let code = MyBackend::generate::<WebsocketClient>();
+
+        assert!(code.contains(
+            "z.object({ exact: z.coerce.bigint().gte(0n).lte(18446744073709551615n), approximate: z.number().finite().int().nonnegative().lte(18446744073709551615) })"
+        ));
+        assert!(code.contains("export interface Balance { exact: bigint, approximate: number }"));
+        assert!(code.contains("_limit: number"));
+        assert!(code.contains("Promise<number>"));
+
+        round_trip(Balance {
+            exact: u64::MAX,
+            approximate: 1,
+        });
+    }
+
+    #[test]
+    fn global_integer_mode() {
+        let options = CodegenOptions::default().integer_mode(IntegerMode::BigInt);
+        let code = MyBackend::generate_with_options::<WebsocketClient>(options);
+
+        assert!(code.contains(
+            "z.object({ exact: z.coerce.bigint().gte(0n).lte(18446744073709551615n), approximate: z.coerce.bigint().gte(0n).lte(18446744073709551615n) })"
+        ));
+        assert!(code.contains("export interface Balance { exact: bigint, approximate: bigint }"));
+        assert!(code.contains("_limit: bigint"));
+        assert!(code.contains("Promise<bigint>"));
+
+        // serde still sends a number which the coerced schema accepts
+        let json = serde_json::to_value(Balance {
+            exact: 1,
+            approximate: u64::MAX,
+        })
+        .unwrap();
+        let schema = Balance::schema().with_integer_mode(IntegerMode::BigInt);
+        assert!(validate_schema(&schema, &json).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "the global integer mode can't be `String`")]
+    fn global_string_mode() {
+        let _ = CodegenOptions::default().integer_mode(IntegerMode::String);
+    }
+}
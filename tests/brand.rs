@@ -0,0 +1,101 @@
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use zod::rpc::{self, clients::WebsocketClient, Backend};
+use zod::validate::assert_conforms;
+use zod::{Namespace, Zod, ZodType};
+
+#[derive(Namespace)]
+#[namespace(name = "Ns")]
+pub struct Ns {}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns", brand)]
+struct UserId(String);
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns", brand = "Order")]
+#[serde(transparent)]
+struct OrderId {
+    id: u32,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct Order {
+    id: OrderId,
+    user: UserId,
+}
+
+#[rpc::namespace]
+impl Ns {}
+
+#[derive(Backend)]
+struct MyBackend(Ns);
+
+#[test]
+fn branded_newtype() {
+    assert_eq!(
+        UserId::schema(),
+        format!("{}.brand<\"UserId\">()", String::schema())
+    );
+    assert_eq!(UserId::type_def(), "string & { __brand: \"UserId\" }");
+    assert_eq!(UserId::input_schema(), UserId::schema());
+    assert_eq!(UserId::input_type_def(), UserId::type_def());
+
+    assert_conforms::<UserId>(&UserId(String::from("a")));
+}
+
+#[test]
+fn explicit_brand() {
+    assert_eq!(
+        OrderId::schema(),
+        format!("{}.brand<\"Order\">()", u32::schema())
+    );
+    assert_eq!(OrderId::type_def(), "number & { __brand: \"Order\" }");
+
+    assert_conforms::<Order>(&Order {
+        id: OrderId { id: 1 },
+        user: UserId(String::from("a")),
+    });
+}
+
+#[test]
+fn codegen() {
+    let code = MyBackend::generate::<WebsocketClient>();
+
+    assert!(code.contains("export type UserId = string & { __brand: \"UserId\" };"));
+    assert!(code.contains("export function parseUserId(value: z.input<typeof UserId>): UserId { return UserId.parse(value) as unknown as UserId; }"));
+    assert!(code.contains("export function parseOrderId(value: z.input<typeof OrderId>): OrderId"));
+    assert!(!code.contains("OrderIdInput"));
+    assert!(!code.contains("parseOrder("));
+}
+
+mod collision {
+    use super::*;
+
+    #[derive(Namespace)]
+    pub struct Brands {}
+
+    #[derive(Zod, Serialize)]
+    #[zod(namespace = "Brands", brand)]
+    struct Email(String);
+
+    #[derive(Zod, Serialize)]
+    #[zod(namespace = "Brands")]
+    #[serde(rename = "parseEmail")]
+    struct Parser {
+        value: bool,
+    }
+
+    #[rpc::namespace]
+    impl Brands {}
+
+    #[derive(Backend)]
+    struct MyBackend(Brands);
+
+    #[test]
+    #[should_panic(expected = "zod: `parseEmail` is declared twice in namespace `Brands`")]
+    fn colliding_parse_helper() {
+        MyBackend::generate::<WebsocketClient>();
+    }
+}
@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use zod::validate::{assert_conforms, validate, validate_input};
+use zod::{Namespace, Zod};
+
+#[derive(Namespace)]
+struct Ns {}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct User {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nick: Option<String>,
+    #[serde(default)]
+    age: u8,
+    tags: HashMap<String, Vec<Tag>>,
+    #[serde(flatten)]
+    meta: Meta,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct Meta {
+    created: u64,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct Tag(String, Option<bool>);
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+enum External {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, String),
+    Struct { value: bool },
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(tag = "type")]
+enum Internal {
+    Unit,
+    Struct { value: bool },
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(tag = "type", content = "content")]
+enum Adjacent {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, String),
+    Struct { value: bool },
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(untagged)]
+enum Untagged {
+    Newtype(u8),
+    Struct { value: bool },
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct Generic<T> {
+    value: T,
+}
+
+fn user() -> User {
+    User {
+        name: String::from("abc"),
+        nick: None,
+        age: 1,
+        tags: HashMap::from([(
+            String::from("a"),
+            vec![Tag(String::from("x"), None), Tag(String::new(), Some(true))],
+        )]),
+        meta: Meta { created: 1 },
+    }
+}
+
+#[test]
+fn structs_conform() {
+    assert_conforms::<User>(&user());
+    assert_conforms::<Tag>(&Tag(String::new(), Some(false)));
+}
+
+#[test]
+fn enums_conform() {
+    for value in [
+        External::Unit,
+        External::Newtype(1),
+        External::Tuple(1, String::new()),
+        External::Struct { value: true },
+    ] {
+        assert_conforms::<External>(&value);
+    }
+
+    for value in [Internal::Unit, Internal::Struct { value: true }] {
+        assert_conforms::<Internal>(&value);
+    }
+
+    for value in [
+        Adjacent::Unit,
+        Adjacent::Newtype(1),
+        Adjacent::Tuple(1, String::new()),
+        Adjacent::Struct { value: true },
+    ] {
+        assert_conforms::<Adjacent>(&value);
+    }
+
+    for value in [Untagged::Newtype(1), Untagged::Struct { value: true }] {
+        assert_conforms::<Untagged>(&value);
+    }
+}
+
+#[test]
+fn generics_conform() {
+    assert_conforms::<Generic<Vec<Tag>>>(&Generic {
+        value: vec![Tag(String::new(), None)],
+    });
+    assert!(validate::<Generic<u8>>(&json!({ "value": "a" })).is_err());
+}
+
+#[test]
+fn drift_is_reported() {
+    let err = validate::<User>(&json!({
+        "name": "abc",
+        "nick": null,
+        "age": 300,
+        "tags": { "a": [["x"]] },
+    }))
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "nick: Expected string, received null, age: Number must be less than or equal to 255, tags.a[0]: Array must contain at least 2 element(s), created: Required"
+    );
+
+    let err = validate::<Internal>(&json!({ "type": "Other" })).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "type: Invalid discriminator value. Expected 'Unit' | 'Struct'"
+    );
+}
+
+#[test]
+fn input_defaults_may_be_missing() {
+    let value = json!({ "name": "abc", "nick": null, "tags": {}, "created": 1 });
+
+    assert!(validate_input::<User>(&value).is_ok());
+    assert!(validate::<User>(&value).is_err());
+}
+
+#[test]
+#[should_panic(expected = "value does not conform to the schema")]
+fn assert_conforms_panics() {
+    assert_conforms::<External>(&json!({ "Newtype": "a" }));
+}
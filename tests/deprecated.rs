@@ -0,0 +1,119 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use futures::channel::mpsc::unbounded;
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use zod::rpc::{self, clients::WebsocketClient, Backend};
+use zod::{__private::codegen::RpcNamespace, Namespace, Zod, ZodType};
+
+#[derive(Namespace)]
+#[namespace(name = "Ns")]
+pub struct Ns {}
+
+/// A user
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[deprecated(note = "use Account")]
+struct User {
+    name: String,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct Account {
+    /// The login name
+    #[deprecated(since = "0.2.0", note = "use id")]
+    name: String,
+    #[deprecated]
+    nick: String,
+    id: u8,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+enum Color {
+    Red,
+    #[deprecated = "use Red"]
+    Crimson,
+}
+
+#[rpc::namespace]
+impl Ns {
+    /// Log in
+    #[deprecated(note = "use login_v2")]
+    pub async fn login(&mut self, _name: String) -> bool {
+        true
+    }
+}
+
+#[derive(Backend)]
+struct MyBackend(Ns);
+
+#[derive(Clone, Default)]
+struct Logs(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Logs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_struct() {
+    assert_eq!(
+        User::docs(),
+        Some("/**\n* A user\n* @deprecated use Account\n*/\n")
+    );
+}
+
+#[test]
+fn deprecated_fields() {
+    assert_eq!(
+        Account::type_def(),
+        "{ /**\n * The login name\n * @deprecated use id\n */ name: string, /** @deprecated */ nick: string, id: number }"
+    );
+    assert!(!Account::schema().to_string().contains("deprecated"));
+}
+
+#[test]
+fn deprecated_variants() {
+    assert_eq!(
+        Color::type_def(),
+        "\"Red\" | /** @deprecated use Red */ \"Crimson\""
+    );
+}
+
+#[test]
+fn deprecated_rpc_method() {
+    let code = MyBackend::generate::<WebsocketClient>();
+
+    assert!(code.contains("/**\n * Log in\n * @deprecated use login_v2\n */\n"));
+    assert!(code.contains("export interface User"));
+}
+
+#[test]
+fn deprecated_rpc_calls_are_logged() {
+    let logs = Logs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(move || writer.clone())
+        .with_ansi(false)
+        .finish();
+
+    let (sender, _receiver) = unbounded();
+    let req: <Ns as RpcNamespace>::Req =
+        serde_json::from_value(serde_json::json!({ "method": "login", "args": ["a"] })).unwrap();
+
+    zod::__private::tracing::subscriber::with_default(subscriber, || {
+        futures::executor::block_on(req.call(1, &mut Ns {}, sender));
+    });
+
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    assert!(logs.contains("WARN zod::deprecated: called a deprecated rpc method method=\"login\""));
+}
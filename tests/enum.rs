@@ -202,7 +202,7 @@ fn enum_adj_tuple_single_variant() {
     let json = serde_json::to_value(Test::A(123)).unwrap();
     assert_eq!(json, serde_json::json!({"type": "A", "content": 123}));
 
-    assert_eq!(Test::schema(), adj_tagged("A", &usize::schema()));
+    assert_eq!(Test::schema(), adj_tagged("A", usize::schema()));
     assert_eq!(Test::type_def(), "{ type: \"A\", content: number }");
     assert_eq!(Test::inline().to_string(), "Ns.Test");
 }
@@ -434,7 +434,7 @@ fn enum_extern_unit() {
     let json = serde_json::to_value(Test::B).unwrap();
     assert_eq!(json, serde_json::json!("B"));
 
-    assert_eq!(Test::schema(), zod_union(&[A, B]));
+    assert_eq!(Test::schema(), "z.enum([\"A\", \"B\"])");
     assert_eq!(Test::type_def(), "\"A\" | \"B\"");
     assert_eq!(Test::inline().to_string(), "Ns.Test");
 }
@@ -449,7 +449,7 @@ fn enum_extern_unit_single_variant() {
     let json = serde_json::to_value(Test::A).unwrap();
     assert_eq!(json, serde_json::json!("A"));
 
-    assert_eq!(Test::schema(), A);
+    assert_eq!(Test::schema(), "z.enum([\"A\"])");
     assert_eq!(Test::type_def(), "\"A\"");
     assert_eq!(Test::inline().to_string(), "Ns.Test");
 }
@@ -697,7 +697,10 @@ fn enum_untagged_tuple_multiple_fields() {
 
     assert_eq!(
         Test::schema(),
-        zod_union(&[tuple(&[usize::schema(), usize::schema()]), String::schema()])
+        zod_union(&[
+            tuple(&[usize::schema(), usize::schema()]),
+            String::schema().to_string()
+        ])
     );
     assert_eq!(Test::type_def(), "[number, number] | string");
     assert_eq!(Test::inline().to_string(), "Ns.Test");
@@ -764,3 +767,21 @@ fn enum_untagged_unit_single_variant() {
     assert_eq!(Test::type_def(), "null");
     assert_eq!(Test::inline().to_string(), "Ns.Test");
 }
+
+#[test]
+fn enum_extern_empty_variants() {
+    test_case! {
+        enum Test {
+            A(),
+            B {},
+        }
+    }
+    let json = serde_json::to_value([Test::A(), Test::B {}]).unwrap();
+    assert_eq!(json, serde_json::json!([{"A": []}, {"B": {}}]));
+
+    assert_eq!(
+        Test::schema(),
+        zod_union(&[object!(A: "z.tuple([])"), object!(B: "z.object({})")])
+    );
+    assert_eq!(Test::type_def(), "{ A: [] } | { B: {} }");
+}
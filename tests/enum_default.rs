@@ -11,9 +11,11 @@ fn serde_skip_enum_tuple_variant() {
         }
     }
 
-    assert!(Test::schema().contains("z.string().optional()"));
+    assert!(Test::input_schema()
+        .to_string()
+        .contains("z.string().optional()"));
     assert_eq!(
-        Test::type_def(),
+        Test::input_type_def(),
         "{ A: string | undefined } | { B: number }"
     );
 }
@@ -32,9 +34,11 @@ fn serde_skip_enum_struct_variant() {
         }
     }
 
-    assert!(Test::schema().contains("z.string().optional()"));
+    assert!(Test::input_schema()
+        .to_string()
+        .contains("z.string().optional()"));
     assert_eq!(
-        Test::type_def(),
+        Test::input_type_def(),
         "{ A: { s?: string | undefined } } | { B: number }"
     )
 }
@@ -49,5 +53,7 @@ fn serde_skip_enum_tuple_field() {
         }
     }
 
-    assert!(Test::schema().contains("z.string().optional()"));
+    assert!(Test::input_schema()
+        .to_string()
+        .contains("z.string().optional()"));
 }
@@ -0,0 +1,33 @@
+use serde_json::json;
+use zod::validate::validate;
+use zod::ZodType;
+
+mod test_utils;
+
+#[test]
+fn empty_enum() {
+    test_case! {
+        #[derive(serde::Deserialize)]
+        enum Test {}
+    }
+
+    assert_eq!(Test::schema(), "z.never()");
+    assert_eq!(Test::type_def(), "never");
+    assert_eq!(Test::input_schema(), "z.never()");
+    assert_eq!(Test::input_type_def(), "never");
+
+    assert!(validate::<Test>(&json!("A")).is_err());
+    assert!(serde_json::from_value::<Test>(json!("A")).is_err());
+}
+
+#[test]
+fn never_error() {
+    test_case! {
+        enum Never {}
+    }
+
+    assert_eq!(
+        <Result<u8, Never>>::type_def(),
+        "{ Ok: number } | { Err: Ns.Never }"
+    );
+}
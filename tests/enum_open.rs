@@ -0,0 +1,89 @@
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use zod::validate::{validate, validate_schema};
+use zod::{Namespace, Zod, ZodType};
+
+#[derive(Namespace)]
+#[namespace(name = "Ns")]
+pub struct Ns {}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(tag = "type")]
+enum Event {
+    Created {
+        id: bool,
+    },
+    Deleted,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns", open)]
+#[non_exhaustive]
+enum Color {
+    Red,
+    Green,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns", open)]
+#[non_exhaustive]
+enum Shape {
+    Point,
+    Circle(bool),
+}
+
+#[test]
+fn serde_other() {
+    let variants = "z.object({ type: z.literal(\"Created\"), id: z.boolean() }), z.object({ type: z.literal(\"Deleted\") }), z.object({ type: z.literal(\"Unknown\") })";
+
+    assert_eq!(
+        Event::schema(),
+        format!("z.discriminatedUnion(\"type\", [{variants}])")
+    );
+    assert_eq!(
+        Event::input_schema(),
+        format!("z.union([z.discriminatedUnion(\"type\", [{variants}]), z.object({{ type: z.string() }})])")
+    );
+    assert_eq!(
+        Event::input_type_def(),
+        "{ type: \"Created\", id: boolean } | { type: \"Deleted\" } | { type: \"Unknown\" } | { type: string & {} }"
+    );
+
+    let added = json!({ "type": "Updated" });
+    assert!(validate_schema(&Event::input_schema(), &added).is_ok());
+    assert!(matches!(
+        serde_json::from_value::<Event>(added).unwrap(),
+        Event::Unknown
+    ));
+}
+
+#[test]
+fn open_unit_enum() {
+    assert_eq!(
+        Color::schema(),
+        "z.union([z.enum([\"Red\", \"Green\"]), z.string()])"
+    );
+    assert_eq!(Color::type_def(), "\"Red\" | \"Green\" | (string & {})");
+    assert_eq!(Color::input_schema(), "z.enum([\"Red\", \"Green\"])");
+
+    assert!(validate::<Color>(&json!("Blue")).is_ok());
+}
+
+#[test]
+fn open_enum_with_data() {
+    assert_eq!(
+        Shape::schema(),
+        "z.union([z.literal(\"Point\"), z.object({ Circle: z.boolean() }), z.string(), z.record(z.string(), z.unknown())])"
+    );
+    assert_eq!(
+        Shape::type_def(),
+        "\"Point\" | { Circle: boolean } | (string & {}) | Record<string, unknown>"
+    );
+
+    assert!(validate::<Shape>(&json!({ "Square": 1 })).is_ok());
+    assert!(validate::<Shape>(&json!(1)).is_err());
+}
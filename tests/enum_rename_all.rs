@@ -19,11 +19,11 @@ fn rename_all_struct() {
     let json = serde_json::to_value(Test::AnotherValue { num: 123 }).unwrap();
     assert_eq!(json, serde_json::json!({"another_value": {"num": 123}}));
 
-    assert!(Test::schema().contains("hello_world"),);
-    assert!(Test::schema().contains("another_value"),);
+    assert!(Test::schema().to_string().contains("hello_world"),);
+    assert!(Test::schema().to_string().contains("another_value"),);
 
-    assert!(Test::type_def().contains("hello_world"),);
-    assert!(Test::type_def().contains("another_value"),);
+    assert!(Test::type_def().to_string().contains("hello_world"),);
+    assert!(Test::type_def().to_string().contains("another_value"),);
 
     assert_eq!(Test::inline().to_string(), "Ns.Test");
 }
@@ -41,11 +41,11 @@ fn rename_all_tuple() {
     let json = serde_json::to_value(Test::AnotherValue(123, 42)).unwrap();
     assert_eq!(json, serde_json::json!({"another_value": [123, 42]}));
 
-    assert!(Test::schema().contains("hello_world"),);
-    assert!(Test::schema().contains("another_value"),);
+    assert!(Test::schema().to_string().contains("hello_world"),);
+    assert!(Test::schema().to_string().contains("another_value"),);
 
-    assert!(Test::type_def().contains("hello_world"),);
-    assert!(Test::type_def().contains("another_value"),);
+    assert!(Test::type_def().to_string().contains("hello_world"),);
+    assert!(Test::type_def().to_string().contains("another_value"),);
 
     assert_eq!(Test::inline().to_string(), "Ns.Test");
 }
@@ -63,11 +63,11 @@ fn rename_all_unit() {
     let json = serde_json::to_value(Test::AnotherValue).unwrap();
     assert_eq!(json, serde_json::json!("another_value"));
 
-    assert!(Test::schema().contains("hello_world"),);
-    assert!(Test::schema().contains("another_value"),);
+    assert!(Test::schema().to_string().contains("hello_world"),);
+    assert!(Test::schema().to_string().contains("another_value"),);
 
-    assert!(Test::type_def().contains("hello_world"),);
-    assert!(Test::type_def().contains("another_value"),);
+    assert!(Test::type_def().to_string().contains("hello_world"),);
+    assert!(Test::type_def().to_string().contains("another_value"),);
 
     assert_eq!(Test::inline().to_string(), "Ns.Test");
 }
@@ -18,13 +18,13 @@ fn rename_variant_struct() {
     let json = serde_json::to_value(Test::AnotherValue { num: 123 }).unwrap();
     assert_eq!(json, serde_json::json!({"after": {"num": 123}}));
 
-    assert!(Test::schema().contains("HelloWorld"),);
-    assert!(Test::schema().contains("after"));
-    assert!(!Test::schema().contains("AnotherValue"));
+    assert!(Test::schema().to_string().contains("HelloWorld"),);
+    assert!(Test::schema().to_string().contains("after"));
+    assert!(!Test::schema().to_string().contains("AnotherValue"));
 
-    assert!(Test::type_def().contains("HelloWorld"),);
-    assert!(Test::type_def().contains("after"));
-    assert!(!Test::type_def().contains("AnotherValue"));
+    assert!(Test::type_def().to_string().contains("HelloWorld"),);
+    assert!(Test::type_def().to_string().contains("after"));
+    assert!(!Test::type_def().to_string().contains("AnotherValue"));
 
     assert_eq!(Test::inline().to_string(), "Ns.Test");
 }
@@ -44,15 +44,15 @@ fn rename_struct_field() {
     let json = serde_json::to_value(Test::AnotherValue { before: 123 }).unwrap();
     assert_eq!(json, serde_json::json!({"AnotherValue": {"after": 123}}));
 
-    assert!(Test::schema().contains("HelloWorld"),);
-    assert!(Test::schema().contains("AnotherValue"),);
-    assert!(Test::schema().contains("after"));
-    assert!(!Test::schema().contains("before"));
+    assert!(Test::schema().to_string().contains("HelloWorld"),);
+    assert!(Test::schema().to_string().contains("AnotherValue"),);
+    assert!(Test::schema().to_string().contains("after"));
+    assert!(!Test::schema().to_string().contains("before"));
 
-    assert!(Test::type_def().contains("HelloWorld"),);
-    assert!(Test::type_def().contains("AnotherValue"),);
-    assert!(Test::type_def().contains("after"));
-    assert!(!Test::type_def().contains("before"));
+    assert!(Test::type_def().to_string().contains("HelloWorld"),);
+    assert!(Test::type_def().to_string().contains("AnotherValue"),);
+    assert!(Test::type_def().to_string().contains("after"));
+    assert!(!Test::type_def().to_string().contains("before"));
     assert_eq!(Test::inline().to_string(), "Ns.Test");
 }
 
@@ -69,13 +69,13 @@ fn rename_all_tuple() {
     let json = serde_json::to_value(Test::AnotherValue(123, 42)).unwrap();
     assert_eq!(json, serde_json::json!({"after": [123, 42]}));
 
-    assert!(Test::schema().contains("HelloWorld"),);
-    assert!(Test::schema().contains("after"));
-    assert!(!Test::schema().contains("before"));
+    assert!(Test::schema().to_string().contains("HelloWorld"),);
+    assert!(Test::schema().to_string().contains("after"));
+    assert!(!Test::schema().to_string().contains("before"));
 
-    assert!(Test::type_def().contains("HelloWorld"),);
-    assert!(Test::type_def().contains("after"),);
-    assert!(!Test::type_def().contains("before"));
+    assert!(Test::type_def().to_string().contains("HelloWorld"),);
+    assert!(Test::type_def().to_string().contains("after"),);
+    assert!(!Test::type_def().to_string().contains("before"));
 
     assert_eq!(Test::inline().to_string(), "Ns.Test");
 }
@@ -93,13 +93,13 @@ fn rename_all_unit() {
     let json = serde_json::to_value(Test::AnotherValue).unwrap();
     assert_eq!(json, serde_json::json!("after"));
 
-    assert!(Test::schema().contains("HelloWorld"),);
-    assert!(Test::schema().contains("after"));
-    assert!(!Test::schema().contains("before"));
+    assert!(Test::schema().to_string().contains("HelloWorld"),);
+    assert!(Test::schema().to_string().contains("after"));
+    assert!(!Test::schema().to_string().contains("before"));
 
-    assert!(Test::type_def().contains("HelloWorld"),);
-    assert!(Test::type_def().contains("after"));
-    assert!(!Test::type_def().contains("before"));
+    assert!(Test::type_def().to_string().contains("HelloWorld"),);
+    assert!(Test::type_def().to_string().contains("after"));
+    assert!(!Test::type_def().to_string().contains("before"));
 
     assert_eq!(Test::inline().to_string(), "Ns.Test");
 }
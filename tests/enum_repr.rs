@@ -0,0 +1,81 @@
+use pretty_assertions::assert_eq;
+use zod::rpc::{self, clients::WebsocketClient, Backend};
+use zod::validate::assert_conforms;
+use zod::{Namespace, Zod, ZodType};
+
+#[derive(Namespace)]
+#[namespace(name = "Ns")]
+pub struct Ns {}
+
+/// what `serde_repr::Serialize_repr` generates
+macro_rules! serialize_repr {
+    ($t:ident as $repr:ident) => {
+        impl serde::Serialize for $t {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&(*self as $repr), serializer)
+            }
+        }
+    };
+}
+
+#[derive(Zod, Clone, Copy)]
+#[zod(namespace = "Ns", repr)]
+#[repr(u8)]
+pub enum Status {
+    Active = 1,
+    Inactive,
+    Banned = 10,
+}
+
+serialize_repr!(Status as u8);
+
+#[derive(Zod, Clone, Copy)]
+#[zod(namespace = "Ns", repr, ts_enum)]
+#[repr(i8)]
+pub enum Direction {
+    Down = -1,
+    Up = 1,
+}
+
+serialize_repr!(Direction as i8);
+
+#[test]
+fn numeric_literals() {
+    assert_eq!(
+        Status::schema(),
+        "z.union([z.literal(1), z.literal(2), z.literal(10)])"
+    );
+    assert_eq!(Status::type_def(), "1 | 2 | 10");
+    assert_eq!(Status::inline().to_string(), "Ns.Status");
+
+    assert_conforms::<Status>(&Status::Inactive);
+    assert_eq!(
+        zod::validate::validate::<Status>(&serde_json::json!("Active"))
+            .unwrap_err()
+            .to_string(),
+        "Invalid enum value. Expected 1 | 2 | 10, received \"Active\""
+    );
+}
+
+#[test]
+fn native_enum() {
+    assert_eq!(Direction::schema(), "z.nativeEnum(Ns.DirectionEnum)");
+    assert_eq!(Direction::type_def(), "Ns.DirectionEnum");
+    assert_conforms::<Direction>(&Direction::Down);
+}
+
+#[rpc::namespace]
+impl Ns {}
+
+#[derive(Backend)]
+struct MyBackend(Ns);
+
+#[test]
+fn codegen() {
+    let code = MyBackend::generate::<WebsocketClient>();
+
+    assert!(code.contains(
+        "export const StatusEnum = { \"Active\": 1, \"Inactive\": 2, \"Banned\": 10 } as const;"
+    ));
+    assert!(code.contains("export enum DirectionEnum { \"Down\" = -1, \"Up\" = 1 }"));
+}
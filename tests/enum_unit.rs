@@ -0,0 +1,116 @@
+use pretty_assertions::assert_eq;
+use zod::rpc::{self, clients::WebsocketClient, Backend};
+use zod::validate::assert_conforms;
+use zod::ZodType;
+
+mod test_utils;
+
+#[test]
+fn renamed_variants() {
+    test_case! {
+        #[serde(rename_all = "kebab-case")]
+        enum Test {
+            LightBlue,
+            DarkRed,
+        }
+    }
+
+    assert_eq!(Test::schema(), "z.enum([\"light-blue\", \"dark-red\"])");
+    assert_eq!(Test::type_def(), "\"light-blue\" | \"dark-red\"");
+    assert_conforms::<Test>(&Test::DarkRed);
+}
+
+#[test]
+fn ts_enum() {
+    #[derive(zod::Namespace)]
+    #[namespace(name = "Ns")]
+    struct Ns {}
+
+    #[derive(zod::Zod, serde::Serialize)]
+    #[zod(namespace = "Ns", ts_enum)]
+    #[allow(dead_code)]
+    enum Test {
+        A,
+        B,
+    }
+
+    assert_eq!(Test::schema(), "z.nativeEnum(Ns.TestEnum)");
+    assert_eq!(Test::type_def(), "Ns.TestEnum");
+    assert_conforms::<Test>(&Test::A);
+}
+
+mod codegen {
+    use super::*;
+
+    #[derive(zod::Namespace)]
+    pub struct Colors {}
+
+    #[derive(zod::Zod, serde::Serialize, serde::Deserialize, Debug)]
+    #[zod(namespace = "Colors")]
+    pub enum Color {
+        Red,
+        Green,
+    }
+
+    #[derive(zod::Zod, serde::Serialize, serde::Deserialize, Debug)]
+    #[zod(namespace = "Colors", ts_enum)]
+    pub enum Shade {
+        Light,
+        Dark,
+    }
+
+    #[rpc::namespace]
+    impl Colors {
+        pub async fn mix(&mut self, _color: Color, _shade: Shade) -> bool {
+            true
+        }
+    }
+
+    #[derive(Backend)]
+    struct MyBackend(Colors);
+
+    #[test]
+    fn const_objects_and_native_enums() {
+        let code = MyBackend::generate::<WebsocketClient>();
+
+        assert!(code.contains(
+            "export const ColorEnum = { \"Red\": \"Red\", \"Green\": \"Green\" } as const;\nexport const Color= z.enum([\"Red\", \"Green\"])"
+        ));
+        assert!(code.contains(
+            "export enum ShadeEnum { \"Light\" = \"Light\", \"Dark\" = \"Dark\" }\nexport const Shade= z.nativeEnum(Colors.ShadeEnum)"
+        ));
+        assert!(code.contains("export type Shade = Colors.ShadeEnum;"));
+    }
+
+    mod collision {
+        use super::*;
+
+        #[derive(zod::Namespace)]
+        pub struct Levels {}
+
+        #[derive(zod::Zod, serde::Serialize)]
+        #[zod(namespace = "Levels")]
+        #[allow(dead_code)]
+        pub enum Level {
+            Low,
+        }
+
+        #[derive(zod::Zod, serde::Serialize)]
+        #[zod(namespace = "Levels")]
+        pub struct LevelEnum {
+            value: bool,
+        }
+
+        #[rpc::namespace]
+        impl Levels {}
+
+        #[derive(Backend)]
+        struct MyBackend(Levels);
+
+        #[test]
+        #[should_panic(expected = "zod: `LevelEnum` is declared twice in namespace `Levels`")]
+        fn colliding_const_object() {
+            MyBackend::generate::<WebsocketClient>();
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use zod::rpc::{self, clients::WebsocketClient, Backend};
+use zod::validate::validate;
+use zod::{Namespace, Zod, ZodType};
+
+#[derive(Namespace)]
+#[namespace(name = "Ns")]
+pub struct Ns {}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct User {
+    /// The login name
+    name: String,
+    /// Admins can
+    /// do anything
+    admin: bool,
+    /// Ends the comment */ early
+    note: bool,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(tag = "type")]
+enum Event {
+    /// A user was created
+    Created {
+        /// The id of the "new" user
+        id: String,
+    },
+    Deleted {
+        id: String,
+    },
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns", ts_enum)]
+enum Color {
+    /// The color of blood
+    Red,
+    Green,
+}
+
+#[rpc::namespace]
+impl Ns {}
+
+#[derive(Backend)]
+struct MyBackend(Ns);
+
+#[test]
+fn field_docs() {
+    assert_eq!(
+        User::schema(),
+        format!(
+            "z.object({{ name: {}.describe(\"The login name\"), admin: z.boolean().describe(\"Admins can\\ndo anything\"), note: z.boolean().describe(\"Ends the comment */ early\") }})",
+            String::schema()
+        )
+    );
+    assert_eq!(
+        User::type_def(),
+        "{ /** The login name */ name: string, /**\n * Admins can\n * do anything\n */ admin: boolean, /** Ends the comment *\\/ early */ note: boolean }"
+    );
+    assert!(validate::<User>(&json!({ "name": "a", "admin": true, "note": false })).is_ok());
+}
+
+#[test]
+fn variant_docs() {
+    assert_eq!(
+        Event::type_def(),
+        "/** A user was created */ { type: \"Created\", /** The id of the \"new\" user */ id: string } | { type: \"Deleted\", id: string }"
+    );
+    assert_eq!(
+        Event::schema(),
+        format!(
+            "z.discriminatedUnion(\"type\", [z.object({{ type: z.literal(\"Created\"), id: {string}.describe(\"The id of the \\\"new\\\" user\") }}).describe(\"A user was created\"), z.object({{ type: z.literal(\"Deleted\"), id: {string} }})])",
+            string = String::schema()
+        )
+    );
+    assert!(validate::<Event>(&json!({ "type": "Created", "id": "a" })).is_ok());
+    assert!(validate::<Event>(&json!({ "type": "Created" })).is_err());
+}
+
+#[test]
+fn codegen() {
+    let code = MyBackend::generate::<WebsocketClient>();
+
+    assert!(code.contains("export enum ColorEnum { /** The color of blood */ \"Red\" = \"Red\", \"Green\" = \"Green\" }"));
+    assert!(code.contains("/** The login name */ name: string"));
+}
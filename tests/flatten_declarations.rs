@@ -0,0 +1,122 @@
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use zod::rpc::{self, clients::WebsocketClient, Backend};
+use zod::validate::{assert_conforms, validate_schema};
+use zod::{Namespace, Zod, ZodType};
+
+#[derive(Namespace)]
+#[namespace(name = "Ns")]
+pub struct Ns {}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct Base {
+    id: bool,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct Extended {
+    #[serde(flatten)]
+    base: Base,
+    name: bool,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct ExtendedTwice {
+    #[serde(flatten)]
+    extended: Extended,
+    value: bool,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(tag = "type")]
+enum Kind {
+    A { a: bool },
+    B { b: bool },
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct WithEnum {
+    #[serde(flatten)]
+    kind: Kind,
+    value: bool,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct WithOption {
+    #[serde(flatten)]
+    base: Option<Base>,
+    value: bool,
+}
+
+#[rpc::namespace]
+impl Ns {}
+
+#[derive(Backend)]
+struct MyBackend(Ns);
+
+/// the input schema accepts every value serde deserializes
+fn accepts<T>(value: serde_json::Value) -> bool
+where
+    T: ZodType + for<'de> Deserialize<'de>,
+{
+    let valid = validate_schema(&T::input_schema(), &value).is_ok();
+    assert_eq!(valid, serde_json::from_value::<T>(value).is_ok());
+    valid
+}
+
+#[test]
+fn flattened_interfaces_are_extended() {
+    let code = MyBackend::generate::<WebsocketClient>();
+
+    assert!(code.contains("export interface Base { id: boolean }"));
+    assert!(code.contains("export interface Extended extends Ns.Base { name: boolean }"));
+    assert!(code.contains("export interface ExtendedTwice extends Ns.Extended { value: boolean }"));
+}
+
+#[test]
+fn flattened_enum() {
+    assert_eq!(WithEnum::type_def(), "{ value: boolean } & Ns.Kind");
+
+    let code = MyBackend::generate::<WebsocketClient>();
+    assert!(code.contains("export type WithEnum = { value: boolean } & Ns.Kind;"));
+    assert!(!code.contains("export interface WithEnum"));
+
+    assert_conforms::<WithEnum>(&WithEnum {
+        kind: Kind::A { a: true },
+        value: false,
+    });
+}
+
+#[test]
+fn flattened_option() {
+    assert_eq!(
+        WithOption::schema(),
+        "z.object({ value: z.boolean() }).and(z.union([z.lazy(() => Ns.Base), z.object({})]))"
+    );
+    assert_eq!(
+        WithOption::type_def(),
+        "{ value: boolean } & (Ns.Base | {})"
+    );
+
+    let code = MyBackend::generate::<WebsocketClient>();
+    assert!(code.contains("export type WithOption = { value: boolean } & (Ns.Base | {});"));
+
+    assert_conforms::<WithOption>(&WithOption {
+        base: None,
+        value: true,
+    });
+    assert_conforms::<WithOption>(&WithOption {
+        base: Some(Base { id: true }),
+        value: true,
+    });
+    assert!(accepts::<WithOption>(json!({ "value": true })));
+    assert!(accepts::<WithOption>(json!({ "value": true, "id": "a" })));
+    assert!(!accepts::<WithOption>(json!({ "id": true })));
+}
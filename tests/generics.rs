@@ -0,0 +1,211 @@
+use pretty_assertions::assert_eq;
+use zod::{NamespaceMemberDefinition, ZodType};
+
+mod test_utils;
+use test_utils::*;
+
+fn member(name: &str) -> &'static NamespaceMemberDefinition {
+    NamespaceMemberDefinition::collect()
+        .remove("Ns")
+        .unwrap_or_default()
+        .into_iter()
+        .find(|def| def.name() == name)
+        .expect("registered member")
+}
+
+#[test]
+fn generic_struct() {
+    test_case! {
+        struct Page<T> {
+            items: Vec<T>,
+            total: usize,
+        }
+    }
+
+    let json = serde_json::to_value(Page {
+        items: vec![String::from("abc")],
+        total: 1,
+    })
+    .unwrap();
+    assert_eq!(json, serde_json::json!({"items": ["abc"], "total": 1}));
+
+    assert_eq!(
+        Page::<String>::schema(),
+        zod_obj(&[
+            ("items", Vec::<String>::schema()),
+            ("total", usize::schema())
+        ])
+    );
+    assert_eq!(
+        Page::<String>::type_def(),
+        "{ items: Array<string>, total: number }"
+    );
+    assert_eq!(Page::<String>::inline().to_string(), "Ns.Page<string>");
+    assert_eq!(
+        Page::<String>::inline_schema(),
+        "z.lazy(() => Ns.Page(z.string()))"
+    );
+
+    let def = member("Page");
+    assert_eq!(def.generics(), &["T"]);
+    assert_eq!(
+        def.schema(),
+        format!(
+            "z.object({{ items: z.array(schemaT), total: {} }})",
+            usize::schema()
+        )
+    );
+    assert_eq!(def.type_def(), "{ items: Array<T>, total: number }");
+}
+
+#[test]
+fn generic_struct_multiple_params() {
+    test_case! {
+        struct Paginated<T, C> {
+            items: Vec<T>,
+            cursor: Option<C>,
+        }
+    }
+
+    assert_eq!(
+        Paginated::<bool, String>::inline().to_string(),
+        "Ns.Paginated<boolean, string>"
+    );
+    assert_eq!(
+        Paginated::<bool, String>::inline_schema(),
+        "z.lazy(() => Ns.Paginated(z.boolean(), z.string()))"
+    );
+
+    let def = member("Paginated");
+    assert_eq!(def.generics(), &["T", "C"]);
+    assert_eq!(def.type_def(), "{ items: Array<T>, cursor: C | null }");
+}
+
+#[test]
+fn generic_struct_nested_instantiation() {
+    test_case! {
+        struct Envelope<T> {
+            data: T,
+        }
+
+        #[derive(zod::Zod, serde::Serialize)]
+        #[zod(namespace = "Ns")]
+        struct User {
+            envelope: Envelope<Vec<usize>>,
+        }
+    }
+
+    assert_eq!(User::type_def(), "{ envelope: Ns.Envelope<Array<number>> }");
+    assert_eq!(
+        User::schema(),
+        format!(
+            "z.object({{ envelope: z.lazy(() => Ns.Envelope({})) }})",
+            Vec::<usize>::schema()
+        )
+    );
+}
+
+#[test]
+fn generic_enum() {
+    test_case! {
+        #[serde(tag = "type", content = "content")]
+        enum Outcome<T, E> {
+            Done(T),
+            Failed(E),
+        }
+    }
+
+    let json = serde_json::to_value(Outcome::<usize, String>::Done(1)).unwrap();
+    assert_eq!(json, serde_json::json!({"type": "Done", "content": 1}));
+
+    assert_eq!(
+        Outcome::<usize, String>::type_def(),
+        "{ type: \"Done\", content: number } | { type: \"Failed\", content: string }"
+    );
+    assert_eq!(
+        Outcome::<usize, String>::inline().to_string(),
+        "Ns.Outcome<number, string>"
+    );
+
+    let def = member("Outcome");
+    assert_eq!(def.generics(), &["T", "E"]);
+    assert_eq!(
+        def.schema(),
+        discriminated_union(
+            "type",
+            &[
+                adj_tagged("Done", "schemaT"),
+                adj_tagged("Failed", "schemaE")
+            ]
+        )
+    );
+}
+
+#[test]
+fn lifetimes() {
+    test_case! {
+        struct Borrowed<'a> {
+            s: &'a str,
+        }
+    }
+
+    assert_eq!(Borrowed::type_def(), "{ s: string }");
+    assert!(member("Borrowed").generics().is_empty());
+}
+
+#[test]
+fn bounds_are_kept() {
+    test_case! {
+        struct Sorted<T: Clone + Ord, K>
+        where
+            K: std::hash::Hash + Eq + zod::ZodMapKey,
+        {
+            items: Vec<T>,
+            index: std::collections::HashMap<K, usize>,
+        }
+    }
+
+    assert_eq!(
+        Sorted::<u8, String>::inline().to_string(),
+        "Ns.Sorted<number, string>"
+    );
+
+    let def = member("Sorted");
+    assert_eq!(def.generics(), &["T", "K"]);
+    assert_eq!(
+        def.type_def(),
+        "{ items: Array<T>, index: Record<K, number> }"
+    );
+}
+
+mod codegen {
+    use zod::rpc::{self, clients::WebsocketClient, Backend};
+    use zod::{Namespace, Zod};
+
+    #[derive(Namespace)]
+    #[namespace(name = "Generic")]
+    pub struct Generic {}
+
+    #[derive(Zod, serde::Serialize)]
+    #[zod(namespace = "Generic")]
+    pub struct Pair<A, B> {
+        pub first: A,
+        pub second: B,
+    }
+
+    #[rpc::namespace]
+    impl Generic {}
+
+    #[derive(Backend)]
+    struct MyBackend(Generic);
+
+    #[test]
+    fn factories_take_schemas() {
+        let code = MyBackend::generate::<WebsocketClient>();
+
+        assert!(code.contains(
+            "export const Pair= <A extends z.ZodTypeAny, B extends z.ZodTypeAny>(schemaA: A, schemaB: B) => z.object({ first: schemaA, second: schemaB })"
+        ));
+        assert!(code.contains("export interface Pair<A, B> { first: A, second: B }"));
+    }
+}
@@ -0,0 +1,209 @@
+use pretty_assertions::assert_eq;
+use zod::ZodType;
+
+mod test_utils;
+use test_utils::*;
+
+#[test]
+fn skip_serializing() {
+    test_case! {
+        #[derive(serde::Deserialize)]
+        struct Test {
+            #[serde(skip_serializing)]
+            password: String,
+            name: String,
+        }
+    }
+
+    let json = serde_json::to_value(Test {
+        password: String::from("secret"),
+        name: String::from("abc"),
+    })
+    .unwrap();
+    assert_eq!(json, serde_json::json!({"name": "abc"}));
+
+    assert_eq!(Test::type_def(), "{ name: string }");
+    assert_eq!(Test::input_type_def(), "{ password: string, name: string }");
+    assert_eq!(Test::inline().to_string(), "Ns.Test");
+    assert_eq!(Test::inline_input().to_string(), "Ns.TestInput");
+    assert_eq!(Test::inline_input_schema(), "z.lazy(() => Ns.TestInput)");
+}
+
+#[test]
+fn directional_renames() {
+    test_case! {
+        #[derive(serde::Deserialize)]
+        enum Test {
+            #[serde(rename(serialize = "out", deserialize = "in"))]
+            A {
+                #[serde(rename(serialize = "y", deserialize = "x"))]
+                value: bool,
+            },
+        }
+    }
+
+    let json = serde_json::to_value(Test::A { value: true }).unwrap();
+    assert_eq!(json, serde_json::json!({"out": {"y": true}}));
+
+    assert_eq!(Test::schema(), object!(out: object!(y: bool::schema())));
+    assert_eq!(
+        Test::input_schema(),
+        object!(in: object!(x: bool::schema()))
+    );
+}
+
+#[test]
+fn serialize_with_keeps_the_field_type() {
+    fn as_string<S: serde::Serializer>(value: &usize, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&value.to_string())
+    }
+
+    test_case! {
+        #[derive(serde::Deserialize)]
+        struct Test {
+            #[serde(serialize_with = "as_string")]
+            value: usize,
+        }
+    }
+
+    assert_eq!(Test::type_def(), "{ value: number }");
+    assert_eq!(Test::schema(), object!(value: usize::schema()));
+    assert_eq!(Test::input_type_def(), "{ value: number }");
+}
+
+#[test]
+fn nested_types_use_the_same_flavour() {
+    test_case! {
+        #[derive(serde::Deserialize)]
+        struct Inner {
+            #[serde(default)]
+            value: bool,
+        }
+
+        #[derive(zod::Zod, serde::Serialize, serde::Deserialize)]
+        #[zod(namespace = "Ns")]
+        struct Outer {
+            inner: Vec<Inner>,
+        }
+    }
+
+    assert_eq!(Outer::type_def(), "{ inner: Array<Ns.Inner> }");
+    assert_eq!(Outer::input_type_def(), "{ inner: Array<Ns.InnerInput> }");
+    assert_eq!(
+        Outer::input_schema(),
+        object!(inner: "z.array(z.lazy(() => Ns.InnerInput))")
+    );
+}
+
+mod codegen {
+    use pretty_assertions::assert_eq;
+    use serde::{Deserialize, Serialize};
+    use zod::rpc::{self, clients::WebsocketClient, Backend};
+    use zod::{Namespace, Zod, ZodType};
+
+    #[derive(Namespace)]
+    pub struct Flavours {}
+
+    #[derive(Zod, Serialize, Deserialize)]
+    #[zod(namespace = "Flavours")]
+    struct Same {
+        value: bool,
+        same: Vec<Same>,
+    }
+
+    #[derive(Zod, Serialize, Deserialize)]
+    #[zod(namespace = "Flavours")]
+    struct Different {
+        #[serde(default)]
+        value: bool,
+    }
+
+    #[derive(Zod, Serialize, Deserialize)]
+    #[zod(namespace = "Flavours")]
+    struct Outer {
+        same: Same,
+        different: Vec<Different>,
+    }
+
+    #[derive(Zod, Serialize, Deserialize)]
+    #[zod(namespace = "Flavours")]
+    struct Left {
+        #[serde(default)]
+        value: bool,
+        right: Option<Box<Right>>,
+    }
+
+    #[derive(Zod, Serialize, Deserialize)]
+    #[zod(namespace = "Flavours")]
+    struct Right {
+        left: Option<Box<Left>>,
+    }
+
+    #[rpc::namespace]
+    impl Flavours {}
+
+    #[derive(Backend)]
+    struct MyBackend(Flavours);
+
+    #[test]
+    fn identical_inputs_are_not_emitted() {
+        assert_eq!(Same::inline_input().to_string(), "Flavours.Same");
+        assert_eq!(
+            Different::inline_input().to_string(),
+            "Flavours.DifferentInput"
+        );
+        assert_eq!(
+            Outer::input_type_def(),
+            "{ same: Flavours.Same, different: Array<Flavours.DifferentInput> }"
+        );
+
+        let code = MyBackend::generate::<WebsocketClient>();
+
+        assert!(!code.contains("SameInput"));
+        assert!(code.contains("export const DifferentInput"));
+        assert!(code.contains("export const OuterInput"));
+    }
+
+    #[test]
+    fn recursive_inputs_differ_through_their_references() {
+        assert_eq!(Left::inline_input().to_string(), "Flavours.LeftInput");
+        assert_eq!(Right::inline_input().to_string(), "Flavours.RightInput");
+        assert_eq!(
+            Right::input_type_def(),
+            "{ left?: Flavours.LeftInput | null | undefined }"
+        );
+    }
+
+    mod collision {
+        use super::*;
+
+        #[derive(Namespace)]
+        pub struct Colliding {}
+
+        #[derive(Zod, Serialize, Deserialize)]
+        #[zod(namespace = "Colliding")]
+        #[allow(dead_code)]
+        struct Foo {
+            #[serde(skip_serializing)]
+            value: bool,
+        }
+
+        #[derive(Zod, Serialize, Deserialize)]
+        #[zod(namespace = "Colliding")]
+        struct FooInput {
+            other: bool,
+        }
+
+        #[rpc::namespace]
+        impl Colliding {}
+
+        #[derive(Backend)]
+        struct MyBackend(Colliding);
+
+        #[test]
+        #[should_panic(expected = "zod: `FooInput` is declared twice in namespace `Colliding`")]
+        fn colliding_names_are_rejected() {
+            MyBackend::generate::<WebsocketClient>();
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use pretty_assertions::assert_eq;
+use std::collections::{BTreeMap, HashMap};
+use zod::ZodType;
+
+mod test_utils;
+
+#[test]
+fn string_keys() {
+    test_case! {
+        struct Test {
+            map: HashMap<String, usize>,
+        }
+    }
+
+    let json = serde_json::to_value(Test {
+        map: HashMap::from([(String::from("a"), 1)]),
+    })
+    .unwrap();
+    assert_eq!(json, serde_json::json!({"map": {"a": 1}}));
+
+    assert_eq!(
+        Test::schema(),
+        format!(
+            "z.object({{ map: z.record(z.string(), {}) }})",
+            usize::schema()
+        )
+    );
+    assert_eq!(Test::type_def(), "{ map: Record<string, number> }");
+}
+
+#[test]
+fn numeric_keys() {
+    test_case! {
+        struct Test {
+            map: BTreeMap<u32, bool>,
+        }
+    }
+
+    let json = serde_json::to_value(Test {
+        map: BTreeMap::from([(1, true)]),
+    })
+    .unwrap();
+    assert_eq!(json, serde_json::json!({"map": {"1": true}}));
+
+    assert_eq!(
+        Test::schema(),
+        r"z.object({ map: z.record(z.string().regex(/^\d+$/), z.boolean()) })"
+    );
+    assert_eq!(Test::type_def(), "{ map: Record<string, boolean> }");
+}
+
+#[test]
+fn unit_enum_keys() {
+    test_case! {
+        #[derive(PartialEq, Eq, Hash)]
+        enum Color {
+            Red,
+            Green,
+        }
+    }
+
+    let json = serde_json::to_value(HashMap::from([(Color::Red, 1)])).unwrap();
+    assert_eq!(json, serde_json::json!({"Red": 1}));
+
+    assert_eq!(
+        HashMap::<Color, bool>::schema(),
+        "z.record(z.lazy(() => Ns.Color), z.boolean())"
+    );
+    assert_eq!(
+        HashMap::<Color, bool>::type_def(),
+        "Partial<Record<Ns.Color, boolean>>"
+    );
+}
+
+#[test]
+fn newtype_keys() {
+    test_case! {
+        #[derive(PartialEq, Eq, Hash)]
+        #[zod(map_key)]
+        struct UserId(u64);
+    }
+
+    let json = serde_json::to_value(HashMap::from([(UserId(7), true)])).unwrap();
+    assert_eq!(json, serde_json::json!({"7": true}));
+
+    assert_eq!(
+        HashMap::<UserId, bool>::schema(),
+        HashMap::<u64, bool>::schema()
+    );
+    assert_eq!(
+        HashMap::<UserId, bool>::type_def(),
+        "Record<string, boolean>"
+    );
+}
+
+#[test]
+fn generic_newtype_keys() {
+    test_case! {
+        #[derive(PartialEq, Eq, Hash)]
+        struct Key<T>(T);
+    }
+
+    assert_eq!(
+        HashMap::<Key<String>, bool>::schema(),
+        HashMap::<String, bool>::schema()
+    );
+}
+
+mod other_newtypes {
+    use zod::{Namespace, Zod};
+
+    #[derive(Namespace)]
+    #[namespace(name = "Ns")]
+    pub struct Ns {}
+
+    #[derive(Zod, serde::Serialize)]
+    #[zod(namespace = "Ns")]
+    pub struct Blob(pub Vec<u8>);
+
+    #[derive(Zod, serde::Serialize)]
+    #[zod(namespace = "Ns")]
+    pub struct Point {
+        pub x: u8,
+    }
+
+    #[derive(Zod, serde::Serialize)]
+    #[zod(namespace = "Ns")]
+    pub struct Wrap(pub Point);
+
+    #[derive(Zod, serde::Serialize)]
+    #[zod(namespace = "Ns")]
+    #[serde(transparent)]
+    pub struct Transparent {
+        pub blob: Blob,
+    }
+}
+
+#[test]
+fn newtypes_over_other_types() {
+    use other_newtypes::{Blob, Transparent, Wrap};
+
+    assert_eq!(Blob::schema(), Vec::<u8>::schema());
+    assert_eq!(Wrap::schema(), "z.lazy(() => Ns.Point)");
+    assert_eq!(Transparent::schema(), "z.lazy(() => Ns.Blob)");
+}
@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use zod::rpc::{self, clients::WebsocketClient, Backend};
+use zod::validate::assert_conforms;
+use zod::{Namespace, Zod, ZodType};
+
+#[derive(Namespace)]
+#[namespace(name = "Ns")]
+pub struct Ns {}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns", remote = "std::time::Duration")]
+#[serde(remote = "Duration")]
+struct DurationDef {
+    #[serde(getter = "Duration::as_secs")]
+    secs: u64,
+    #[serde(getter = "Duration::subsec_nanos")]
+    nanos: u32,
+}
+
+impl From<DurationDef> for Duration {
+    fn from(value: DurationDef) -> Self {
+        Duration::new(value.secs, value.nanos)
+    }
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct Job {
+    #[serde(with = "DurationDef")]
+    #[zod(with = "DurationDef")]
+    timeout: Duration,
+}
+
+#[test]
+fn mirror_is_named_like_the_remote_type() {
+    assert_eq!(
+        DurationDef::schema(),
+        format!(
+            "z.object({{ secs: {}, nanos: {} }})",
+            u64::schema(),
+            u32::schema()
+        )
+    );
+    assert_eq!(DurationDef::inline().to_string(), "Ns.Duration");
+    assert_eq!(DurationDef::inline_input().to_string(), "Ns.Duration");
+}
+
+#[test]
+fn fields_use_the_mirror() {
+    assert_eq!(
+        Job::schema(),
+        "z.object({ timeout: z.lazy(() => Ns.Duration) })"
+    );
+    assert_eq!(Job::type_def(), "{ timeout: Ns.Duration }");
+
+    assert_conforms::<Job>(&Job {
+        timeout: Duration::from_millis(1500),
+    });
+}
+
+#[rpc::namespace]
+impl Ns {}
+
+#[derive(Backend)]
+struct MyBackend(Ns);
+
+#[test]
+fn codegen() {
+    let code = MyBackend::generate::<WebsocketClient>();
+
+    assert!(code.contains("export const Duration= z.object({ secs: "));
+    assert!(code.contains("export interface Duration { secs: number, nanos: number }"));
+    assert!(!code.contains("DurationDef"));
+}
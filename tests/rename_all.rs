@@ -0,0 +1,82 @@
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use zod::validate::assert_conforms;
+use zod::{Namespace, Zod, ZodType};
+
+#[derive(Namespace)]
+#[namespace(name = "Ns")]
+pub struct Ns {}
+
+/// a struct and an enum for every convention, `key` is how the renamed field is emitted
+macro_rules! conventions {
+    ($($convention:tt => $struct_name:ident($key:expr), $enum_name:ident($variant:expr);)*) => {
+        $(
+            #[derive(Zod, Serialize, Deserialize)]
+            #[zod(namespace = "Ns")]
+            #[serde(rename_all = $convention)]
+            struct $struct_name {
+                first_name: bool,
+            }
+
+            #[derive(Zod, Serialize, Deserialize)]
+            #[zod(namespace = "Ns")]
+            #[serde(rename_all = $convention)]
+            enum $enum_name {
+                FirstVariant,
+            }
+        )*
+
+        #[test]
+        fn struct_fields() {
+            $(
+                assert_eq!($struct_name::schema(), format!("z.object({{ {}: z.boolean() }})", $key));
+                assert_eq!($struct_name::type_def(), format!("{{ {}: boolean }}", $key));
+                assert_conforms::<$struct_name>(&$struct_name { first_name: true });
+            )*
+        }
+
+        #[test]
+        fn enum_variants() {
+            $(
+                assert_eq!($enum_name::schema(), format!("z.enum([\"{}\"])", $variant));
+                assert_eq!($enum_name::type_def(), format!("\"{}\"", $variant));
+                assert_conforms::<$enum_name>(&$enum_name::FirstVariant);
+            )*
+        }
+    };
+}
+
+conventions! {
+    "lowercase" => Lower("first_name"), LowerEnum("firstvariant");
+    "UPPERCASE" => Upper("FIRST_NAME"), UpperEnum("FIRSTVARIANT");
+    "PascalCase" => Pascal("FirstName"), PascalEnum("FirstVariant");
+    "camelCase" => Camel("firstName"), CamelEnum("firstVariant");
+    "snake_case" => Snake("first_name"), SnakeEnum("first_variant");
+    "SCREAMING_SNAKE_CASE" => ScreamingSnake("FIRST_NAME"), ScreamingSnakeEnum("FIRST_VARIANT");
+    "kebab-case" => Kebab("\"first-name\""), KebabEnum("first-variant");
+    "SCREAMING-KEBAB-CASE" => ScreamingKebab("\"FIRST-NAME\""), ScreamingKebabEnum("FIRST-VARIANT");
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(tag = "kind-of")]
+enum Quoted {
+    #[serde(rename = "say \"hi\"")]
+    Greeting {
+        #[serde(rename = "content-type")]
+        content_type: bool,
+    },
+}
+
+#[test]
+fn quoted_keys_and_literals() {
+    assert_eq!(
+        Quoted::schema(),
+        "z.object({ \"kind-of\": z.literal(\"say \\\"hi\\\"\"), \"content-type\": z.boolean() })"
+    );
+    assert_eq!(
+        Quoted::type_def(),
+        "{ \"kind-of\": \"say \\\"hi\\\"\", \"content-type\": boolean }"
+    );
+    assert_conforms::<Quoted>(&Quoted::Greeting { content_type: true });
+}
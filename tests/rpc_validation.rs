@@ -0,0 +1,66 @@
+use futures::channel::mpsc::unbounded;
+use futures::StreamExt;
+use zod::{__private::codegen::RpcNamespace, rpc, Namespace, Zod};
+
+#[derive(Namespace)]
+struct Ns {
+    calls: usize,
+}
+
+#[derive(Zod, serde::Deserialize, serde::Serialize, Debug)]
+#[zod(namespace = "Ns", validate)]
+struct User {
+    #[zod(min_length = 3)]
+    name: String,
+}
+
+#[rpc::namespace]
+impl Ns {
+    pub async fn register(&mut self, user: User, _note: String) -> usize {
+        let _ = user;
+        self.calls += 1;
+        self.calls
+    }
+}
+
+fn call(ns: &mut Ns, args: serde_json::Value) -> serde_json::Value {
+    let (sender, mut receiver) = unbounded();
+    let req: <Ns as RpcNamespace>::Req =
+        serde_json::from_value(serde_json::json!({ "method": "register", "args": args })).unwrap();
+
+    futures::executor::block_on(async {
+        req.call(1, ns, sender).await;
+        serde_json::to_value(receiver.next().await.unwrap()).unwrap()
+    })
+}
+
+#[test]
+fn valid_arguments_are_passed_to_the_method() {
+    let mut ns = Ns { calls: 0 };
+    let res = call(&mut ns, serde_json::json!([{ "name": "abc" }, ""]));
+
+    assert_eq!(res, serde_json::json!({ "method": { "id": 1, "data": 1 } }));
+    assert_eq!(ns.calls, 1);
+}
+
+#[test]
+fn invalid_arguments_are_rejected() {
+    let mut ns = Ns { calls: 0 };
+    let res = call(&mut ns, serde_json::json!([{ "name": "ab" }, ""]));
+
+    assert_eq!(
+        res,
+        serde_json::json!({
+            "error": {
+                "id": 1,
+                "data": {
+                    "ValidationError": [{
+                        "path": ["user", "name"],
+                        "message": "must contain at least 3 character(s)"
+                    }]
+                }
+            }
+        })
+    );
+    assert_eq!(ns.calls, 0);
+}
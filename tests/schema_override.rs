@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use zod::ast::ZodSchema;
+use zod::validate::assert_conforms;
+use zod::{Namespace, Zod, ZodType};
+
+#[derive(Namespace)]
+#[namespace(name = "Ns")]
+pub struct Ns {}
+
+mod millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(value.as_millis() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_millis)
+    }
+}
+
+fn timestamp_schema() -> ZodSchema {
+    ZodSchema::Raw(String::from("z.coerce.date()"))
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct Job {
+    #[serde(with = "millis")]
+    #[zod(as = "u32")]
+    timeout: Duration,
+    #[zod(schema = "z.string().datetime()", ts = "string")]
+    created: String,
+    #[zod(schema_with = "timestamp_schema", ts = "Date")]
+    updated: String,
+    #[zod(schema = "z.any()")]
+    payload: String,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+enum Task {
+    Sleep(
+        #[serde(with = "millis")]
+        #[zod(as = "u32")]
+        Duration,
+    ),
+    Run {
+        #[zod(schema = "z.string().url()", ts = "string")]
+        url: String,
+    },
+}
+
+#[test]
+fn struct_fields() {
+    assert_eq!(
+        Job::schema(),
+        format!("z.object({{ timeout: {}, created: z.string().datetime(), updated: z.coerce.date(), payload: z.any() }})", u32::schema())
+    );
+    assert_eq!(
+        Job::type_def(),
+        "{ timeout: number, created: string, updated: Date, payload: unknown }"
+    );
+
+    assert_conforms::<Job>(&Job {
+        timeout: Duration::from_secs(1),
+        created: String::from("2023-01-01T00:00:00Z"),
+        updated: String::from("2023-01-01T00:00:00Z"),
+        payload: String::new(),
+    });
+}
+
+#[test]
+fn variant_fields() {
+    assert_eq!(
+        Task::schema(),
+        format!(
+            "z.union([z.object({{ Sleep: {} }}), z.object({{ Run: z.object({{ url: z.string().url() }}) }})])",
+            u32::schema()
+        )
+    );
+    assert_eq!(
+        Task::type_def(),
+        "{ Sleep: number } | { Run: { url: string } }"
+    );
+
+    assert_conforms::<Task>(&Task::Sleep(Duration::from_millis(10)));
+}
@@ -14,8 +14,14 @@ fn serde_default_named_struct_field() {
         }
     }
 
-    assert!(Test::schema().contains("z.string().optional()"));
-    assert_eq!(Test::type_def(), "{s?: string | undefined,\nnum: number}")
+    assert!(Test::input_schema()
+        .to_string()
+        .contains("z.string().optional()"));
+    assert_eq!(
+        Test::input_type_def(),
+        "{ s?: string | undefined, num: number }"
+    );
+    assert_eq!(Test::type_def(), "{ s: string, num: number }")
 }
 
 #[test]
@@ -25,8 +31,10 @@ fn serde_default_tuple_struct_field() {
         struct Test(#[serde(default)] String);
     }
 
-    assert!(Test::schema().contains("z.string().optional()"));
-    assert_eq!(Test::type_def(), "string | undefined")
+    assert!(Test::input_schema()
+        .to_string()
+        .contains("z.string().optional()"));
+    assert_eq!(Test::input_type_def(), "string | undefined")
 }
 
 #[test]
@@ -36,7 +44,60 @@ fn flatten() {
         struct Test(#[serde(default)]usize);
     }
 
-    assert_eq!(Test::schema(), optional(usize::schema()));
-    assert_eq!(Test::type_def(), "number | undefined");
-    assert_eq!(Test::inline().to_string(), "Ns.Test")
+    assert_eq!(Test::input_schema(), optional(usize::schema()));
+    assert_eq!(Test::input_type_def(), "number | undefined");
+    assert_eq!(Test::inline_input().to_string(), "Ns.TestInput")
+}
+
+fn default_port() -> u16 {
+    8080
+}
+
+#[test]
+fn zod_default() {
+    test_case! {
+        #[derive(serde::Deserialize)]
+        struct Test {
+            #[serde(default)]
+            #[zod(default)]
+            tags: Vec<String>,
+            #[serde(default = "default_port")]
+            #[zod(default)]
+            port: u16,
+            #[serde(default)]
+            #[zod(default)]
+            name: Option<String>,
+        }
+    }
+
+    assert_eq!(
+        Test::input_schema(),
+        zod_obj(&[
+            ("tags", format!("z.array({}).default([])", String::schema())),
+            ("port", format!("{}.default(8080)", u16::schema())),
+            (
+                "name",
+                format!("{}.nullish().default(null)", String::schema())
+            ),
+        ])
+    );
+    assert_eq!(
+        Test::input_type_def(),
+        "{ tags?: Array<string> | undefined, port?: number | undefined, name?: string | null | undefined }"
+    );
+    assert!(zod::validate::validate_schema(&Test::input_schema(), &serde_json::json!({})).is_ok());
+}
+
+#[test]
+fn zod_default_tuple_struct_field() {
+    test_case! {
+        #[derive(serde::Deserialize)]
+        struct Test(#[serde(default)] #[zod(default)] String);
+    }
+
+    assert_eq!(
+        Test::input_schema(),
+        format!("{}.default(\"\")", String::schema())
+    );
+    assert_eq!(Test::schema(), String::schema());
 }
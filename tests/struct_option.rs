@@ -0,0 +1,161 @@
+use pretty_assertions::assert_eq;
+use zod::ZodType;
+
+mod test_utils;
+use test_utils::*;
+
+#[test]
+fn nullable_by_default() {
+    test_case! {
+        struct Test {
+            value: Option<String>,
+        }
+    }
+
+    let json = serde_json::to_value(Test { value: None }).unwrap();
+    assert_eq!(json, serde_json::json!({"value": null}));
+
+    assert_eq!(Test::schema(), object!(value: "z.string().nullable()"));
+    assert_eq!(Test::type_def(), "{ value: string | null }");
+}
+
+#[test]
+fn skip_serializing_none() {
+    test_case! {
+        struct Test {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            value: Option<String>,
+        }
+    }
+
+    let json = serde_json::to_value(Test { value: None }).unwrap();
+    assert_eq!(json, serde_json::json!({}));
+
+    assert_eq!(Test::schema(), object!(value: "z.string().optional()"));
+    assert_eq!(Test::type_def(), "{ value?: string | undefined }");
+}
+
+#[test]
+fn default_input_is_nullish() {
+    test_case! {
+        #[derive(serde::Deserialize)]
+        struct Test {
+            #[serde(default)]
+            value: Option<String>,
+        }
+    }
+
+    let json = serde_json::to_value(Test { value: None }).unwrap();
+    assert_eq!(json, serde_json::json!({"value": null}));
+    assert!(serde_json::from_value::<Test>(serde_json::json!({})).is_ok());
+
+    assert_eq!(Test::schema(), object!(value: "z.string().nullable()"));
+    assert_eq!(Test::type_def(), "{ value: string | null }");
+    assert_eq!(Test::input_schema(), object!(value: "z.string().nullish()"));
+    assert_eq!(
+        Test::input_type_def(),
+        "{ value?: string | null | undefined }"
+    );
+}
+
+#[test]
+fn missing_input_is_none() {
+    test_case! {
+        #[derive(serde::Deserialize)]
+        struct Test {
+            value: Option<String>,
+        }
+    }
+
+    assert!(serde_json::from_value::<Test>(serde_json::json!({})).is_ok());
+
+    assert_eq!(Test::schema(), object!(value: "z.string().nullable()"));
+    assert_eq!(Test::input_schema(), object!(value: "z.string().nullish()"));
+    assert_eq!(
+        Test::input_type_def(),
+        "{ value?: string | null | undefined }"
+    );
+}
+
+#[test]
+fn missing_input_with_deserialize_with() {
+    fn deserialize<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde::Deserialize::deserialize(deserializer)
+    }
+
+    test_case! {
+        #[derive(serde::Deserialize)]
+        struct Test {
+            #[serde(deserialize_with = "deserialize")]
+            value: Option<String>,
+        }
+    }
+
+    assert!(serde_json::from_value::<Test>(serde_json::json!({})).is_err());
+
+    assert_eq!(
+        Test::input_schema(),
+        object!(value: "z.string().nullable()")
+    );
+    assert_eq!(Test::input_type_def(), "{ value: string | null }");
+}
+
+#[test]
+fn missing_tuple_element() {
+    test_case! {
+        #[derive(serde::Deserialize)]
+        struct Test(Option<String>, u8);
+    }
+
+    assert!(serde_json::from_value::<Test>(serde_json::json!([])).is_err());
+
+    assert_eq!(
+        Test::input_schema(),
+        tuple(&[
+            "z.string().nullable()".to_string(),
+            u8::schema().to_string()
+        ])
+    );
+}
+
+#[test]
+fn container_override() {
+    test_case! {
+        #[zod(option = "optional")]
+        struct Test {
+            value: Option<String>,
+            other: Vec<Option<bool>>,
+        }
+    }
+
+    assert_eq!(
+        Test::schema(),
+        object!(value: "z.string().optional()", other: "z.array(z.boolean().nullable())")
+    );
+    assert_eq!(
+        Test::type_def(),
+        "{ value?: string | undefined, other: Array<boolean | null> }"
+    );
+}
+
+#[test]
+fn container_override_nullish() {
+    test_case! {
+        #[zod(option = "nullish")]
+        enum Test {
+            A { value: Option<u8> },
+        }
+    }
+
+    assert_eq!(
+        Test::schema(),
+        object!(A: object!(value: format!("{}.nullish()", u8::schema())))
+    );
+    assert_eq!(
+        Test::type_def(),
+        "{ A: { value?: number | null | undefined } }"
+    );
+}
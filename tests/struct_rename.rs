@@ -35,7 +35,7 @@ fn serde_rename_struct_field() {
         }
     }
 
-    assert!(Test::schema().contains("after"));
-    assert!(Test::schema().contains("other"));
-    assert!(!Test::schema().contains("before"));
+    assert!(Test::schema().to_string().contains("after"));
+    assert!(Test::schema().to_string().contains("other"));
+    assert!(!Test::schema().to_string().contains("before"));
 }
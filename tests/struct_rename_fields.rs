@@ -25,11 +25,11 @@ fn rename_all_struct() {
         serde_json::json!({"after": "abc", "usize_value": 123})
     );
 
-    assert!(Test::schema().contains("after"),);
-    assert!(!Test::schema().contains("before"),);
+    assert!(Test::schema().to_string().contains("after"),);
+    assert!(!Test::schema().to_string().contains("before"),);
 
-    assert!(Test::type_def().contains("after"),);
-    assert!(!Test::type_def().contains("before"),);
+    assert!(Test::type_def().to_string().contains("after"),);
+    assert!(!Test::type_def().to_string().contains("before"),);
 
     assert_eq!(Test::inline().to_string(), "Ns.Test");
 }
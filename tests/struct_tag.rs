@@ -0,0 +1,65 @@
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use zod::validate::{assert_conforms, validate, validate_schema};
+use zod::{Namespace, Zod, ZodType};
+
+#[derive(Namespace)]
+#[namespace(name = "Ns")]
+pub struct Ns {}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(tag = "type")]
+struct Created {
+    id: u8,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(tag = "kind", rename = "deleted")]
+struct Deleted {
+    id: u8,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(untagged)]
+enum Event {
+    Created(Created),
+    Deleted(Deleted),
+}
+
+#[test]
+fn tag_field() {
+    assert_eq!(
+        Created::schema(),
+        format!(
+            "z.object({{ type: z.literal(\"Created\"), id: {} }})",
+            u8::schema()
+        )
+    );
+    assert_eq!(Created::type_def(), "{ type: \"Created\", id: number }");
+    assert_eq!(Deleted::type_def(), "{ kind: \"deleted\", id: number }");
+
+    assert_conforms::<Created>(&Created { id: 1 });
+    assert!(validate::<Created>(&json!({ "type": "Other", "id": 1 })).is_err());
+    assert!(validate::<Created>(&json!({ "id": 1 })).is_err());
+}
+
+#[test]
+fn input_tag_is_optional() {
+    assert_eq!(
+        Created::input_type_def(),
+        "{ type?: \"Created\" | undefined, id: number }"
+    );
+    assert!(validate_schema(&Created::input_schema(), &json!({ "id": 1 })).is_ok());
+    assert!(serde_json::from_value::<Created>(json!({ "id": 1 })).is_ok());
+}
+
+#[test]
+fn untagged_union_of_tagged_structs() {
+    assert_conforms::<Event>(&Event::Created(Created { id: 1 }));
+    assert_conforms::<Event>(&Event::Deleted(Deleted { id: 1 }));
+    assert_eq!(Event::type_def().to_string(), "Ns.Created | Ns.deleted");
+}
@@ -22,6 +22,21 @@ fn ok() {
     assert_eq!(Test::inline().to_string(), "Ns.Test")
 }
 
+#[test]
+fn empty() {
+    test_case! {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Test();
+    }
+
+    let json = serde_json::to_value(Test()).unwrap();
+    assert_eq!(json, serde_json::json!([]));
+    assert_eq!(serde_json::from_value::<Test>(json).unwrap(), Test());
+
+    assert_eq!(Test::schema(), "z.tuple([])");
+    assert_eq!(Test::type_def(), "[]");
+}
+
 #[test]
 fn with_default_fields() {
     test_case! {
@@ -36,10 +51,12 @@ fn with_default_fields() {
     assert_eq!(test, res);
 
     assert_eq!(
-        Test::schema(),
-        tuple(&[usize::schema(), optional(usize::schema())])
+        Test::input_schema(),
+        tuple(&[usize::schema().to_string(), optional(usize::schema())])
     );
 
-    assert_eq!(Test::type_def(), "[number, number | undefined]");
+    assert_eq!(Test::input_type_def(), "[number, number | undefined]");
+    assert_eq!(Test::inline_input().to_string(), "Ns.TestInput");
+    assert_eq!(Test::type_def(), "[number, number]");
     assert_eq!(Test::inline().to_string(), "Ns.Test")
 }
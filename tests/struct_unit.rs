@@ -0,0 +1,24 @@
+use serde_json::json;
+use zod::validate::{assert_conforms, validate};
+use zod::ZodType;
+
+mod test_utils;
+use test_utils::*;
+
+#[test]
+fn unit_struct() {
+    test_case! {
+        #[derive(serde::Deserialize)]
+        struct Test;
+    }
+
+    assert_eq!(serde_json::to_value(Test).unwrap(), json!(null));
+
+    assert_eq!(Test::schema(), NULL);
+    assert_eq!(Test::type_def(), "null");
+    assert_eq!(Test::input_schema(), NULL);
+    assert_eq!(Test::inline().to_string(), "Ns.Test");
+
+    assert_conforms::<Test>(&Test);
+    assert!(validate::<Test>(&json!({})).is_err());
+}
@@ -4,24 +4,24 @@ pub const A: &str = "z.literal(\"A\")";
 pub const B: &str = "z.literal(\"B\")";
 pub const NULL: &str = "z.null()";
 
-pub fn discriminated_union(t: impl AsRef<str>, items: &[impl AsRef<str>]) -> String {
+pub fn discriminated_union(t: impl std::fmt::Display, items: &[impl std::fmt::Display]) -> String {
     format!(
         "z.discriminatedUnion(\"{}\", [{}])",
-        t.as_ref(),
+        t,
         items
             .iter()
-            .map(|i| i.as_ref())
+            .map(|i| i.to_string())
             .collect::<Vec<_>>()
             .join(", ")
     )
 }
 
-pub fn zod_union(items: &[impl AsRef<str>]) -> String {
+pub fn zod_union(items: &[impl std::fmt::Display]) -> String {
     format!(
         "z.union([{}])",
         items
             .iter()
-            .map(|i| i.as_ref())
+            .map(|i| i.to_string())
             .collect::<Vec<_>>()
             .join(", ")
     )
@@ -30,33 +30,33 @@ pub fn zod_union(items: &[impl AsRef<str>]) -> String {
 #[macro_export]
 macro_rules! object {
     ($($k: tt: $v:expr),*) => {
-        zod_obj(&[$((stringify!($k), $v)),*])
+        zod_obj(&[$((stringify!($k), $v.to_string())),*])
     };
 }
 
-pub fn zod_obj(fields: &[(impl AsRef<str>, impl AsRef<str>)]) -> String {
+pub fn zod_obj(fields: &[(impl std::fmt::Display, impl std::fmt::Display)]) -> String {
     let inner = fields
         .iter()
-        .map(|(k, v)| format!("{}: {}", k.as_ref(), v.as_ref()))
+        .map(|(k, v)| format!("{}: {}", k, v))
         .collect::<Vec<_>>();
 
     format!("z.object({{ {} }})", inner.join(", "))
 }
 
-pub fn tuple(fields: &[impl AsRef<str>]) -> String {
-    let inner = fields.iter().map(|f| f.as_ref()).collect::<Vec<_>>();
+pub fn tuple(fields: &[impl std::fmt::Display]) -> String {
+    let inner = fields.iter().map(|f| f.to_string()).collect::<Vec<_>>();
     format!("z.tuple([{}])", inner.join(", "))
 }
 
-pub fn adj_tagged(variant: &str, inner: impl AsRef<str>) -> String {
+pub fn adj_tagged(variant: &str, inner: impl std::fmt::Display) -> String {
     zod_obj(&[
         ("type", format!("z.literal(\"{variant}\")")),
-        ("content", inner.as_ref().to_string()),
+        ("content", inner.to_string()),
     ])
 }
 
-pub fn optional(inner: impl AsRef<str>) -> String {
-    format!("{}.optional()", inner.as_ref())
+pub fn optional(inner: impl std::fmt::Display) -> String {
+    format!("{}.optional()", inner)
 }
 
 #[macro_export]
@@ -0,0 +1,21 @@
+use zod::{Namespace, Zod};
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns")]
+struct Plain {
+    #[zod(bigint)]
+    value: u64,
+}
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns")]
+struct SerializeOnly {
+    #[zod(as_string)]
+    #[serde(serialize_with = "zod::bigint::as_string::serialize")]
+    value: u64,
+}
+
+#[derive(Namespace)]
+struct Ns {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use zod::{Namespace, Zod};
+
+#[derive(Namespace)]
+struct Ns;
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns", brand)]
+struct Test {
+    a: String,
+    b: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use zod::{Namespace, Zod};
+
+#[derive(Namespace)]
+struct Ns;
+
+#[derive(Zod, serde::Deserialize)]
+#[zod(namespace = "Ns")]
+struct Test {
+    #[zod(default)]
+    value: String,
+}
+
+fn main() {}
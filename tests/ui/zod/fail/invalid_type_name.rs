@@ -0,0 +1,20 @@
+use zod::{Namespace, Zod};
+
+#[derive(Namespace)]
+struct Ns;
+
+#[derive(Zod, serde::Serialize, serde::Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(rename = "class")]
+struct Reserved {
+    value: bool,
+}
+
+#[derive(Zod, serde::Serialize, serde::Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(rename = "my-type")]
+struct NotAnIdentifier {
+    value: bool,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+use zod::{Namespace, Zod};
+
+#[derive(Zod, serde::Deserialize)]
+#[zod(namespace = "Ns")]
+struct Test {
+    map: HashMap<Vec<u8>, bool>,
+}
+
+#[derive(Namespace)]
+struct Ns {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use zod::{Namespace, Zod};
+
+#[derive(Namespace)]
+struct Ns;
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns", open)]
+enum Test {
+    A,
+    B,
+}
+
+fn main() {}
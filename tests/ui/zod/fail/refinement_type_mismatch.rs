@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use zod::{Namespace, Zod};
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns")]
+struct EmailOnNumber {
+    #[zod(email)]
+    value: u32,
+}
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns")]
+struct RegexOnSequence {
+    #[zod(regex = "^a$")]
+    value: Vec<String>,
+}
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns")]
+struct MinOnString {
+    #[zod(min = 1)]
+    value: Option<String>,
+}
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns")]
+struct LengthOnReference {
+    #[zod(min_length = 1)]
+    value: EmailOnNumber,
+}
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns")]
+struct LengthOnMap {
+    #[zod(non_empty)]
+    value: HashMap<String, bool>,
+}
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns")]
+struct MaxOnBigint {
+    #[zod(bigint, max = 10)]
+    #[serde(with = "zod::bigint::as_string")]
+    value: u64,
+}
+
+#[derive(Namespace)]
+struct Ns {}
+
+fn main() {}
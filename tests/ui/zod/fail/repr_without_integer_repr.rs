@@ -0,0 +1,13 @@
+use zod::{Namespace, Zod};
+
+#[derive(Namespace)]
+struct Ns;
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns", repr)]
+enum Test {
+    A = 1,
+    B = 2,
+}
+
+fn main() {}
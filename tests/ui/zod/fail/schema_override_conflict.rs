@@ -0,0 +1,13 @@
+use zod::{Namespace, Zod};
+
+#[derive(Namespace)]
+struct Ns;
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns")]
+struct Test {
+    #[zod(as = "u8", schema = "z.number()")]
+    value: u16,
+}
+
+fn main() {}
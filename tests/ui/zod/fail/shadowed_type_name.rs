@@ -0,0 +1,23 @@
+use zod::{Namespace, Zod};
+
+#[derive(Namespace)]
+struct Ns;
+
+#[derive(Zod, serde::Serialize, serde::Deserialize)]
+#[zod(namespace = "Ns")]
+struct Record {
+    value: bool,
+}
+
+#[derive(Zod, serde::Serialize, serde::Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(rename = "Promise")]
+struct Pending {
+    value: bool,
+}
+
+#[derive(Namespace)]
+#[namespace(name = "z")]
+struct Schemas;
+
+fn main() {}
@@ -0,0 +1,13 @@
+use zod::{Namespace, Zod};
+
+#[derive(Namespace)]
+struct Ns;
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns", ts_enum)]
+enum Test {
+    A,
+    B(String),
+}
+
+fn main() {}
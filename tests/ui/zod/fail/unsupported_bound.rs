@@ -0,0 +1,14 @@
+use zod::{Namespace, Zod};
+
+trait Entity {}
+
+#[derive(Zod, serde::Serialize)]
+#[zod(namespace = "Ns")]
+struct Page<T: Entity> {
+    items: Vec<T>,
+}
+
+#[derive(Namespace)]
+struct Ns {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use zod::{Namespace, Zod};
+
+#[derive(Zod, serde::Deserialize)]
+#[zod(namespace = "Ns")]
+struct Test {
+    #[zod(regex = "^(?!admin).*$")]
+    name: String,
+}
+
+#[derive(Namespace)]
+struct Ns {}
+
+fn main() {}
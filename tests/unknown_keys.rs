@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use zod::validate::{assert_conforms, validate};
+use zod::{Namespace, Zod, ZodType};
+
+#[derive(Namespace)]
+#[namespace(name = "Ns")]
+pub struct Ns {}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(deny_unknown_fields)]
+struct Strict {
+    value: bool,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns", unknown_keys = "passthrough")]
+struct Passthrough {
+    value: bool,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns", unknown_keys = "strip")]
+#[serde(deny_unknown_fields)]
+struct Overridden {
+    value: bool,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+struct Extra {
+    value: bool,
+    #[serde(flatten)]
+    extra: HashMap<String, u8>,
+}
+
+#[derive(Zod, Serialize, Deserialize)]
+#[zod(namespace = "Ns")]
+#[serde(tag = "type", deny_unknown_fields)]
+enum Event {
+    Created {
+        id: u8,
+    },
+    #[zod(unknown_keys = "passthrough")]
+    Deleted {
+        id: u8,
+    },
+}
+
+#[test]
+fn deny_unknown_fields() {
+    assert_eq!(
+        Strict::schema(),
+        "z.object({ value: z.boolean() }).strict()"
+    );
+    assert_eq!(Strict::input_schema(), Strict::schema());
+    assert_eq!(Strict::type_def(), "{ value: boolean }");
+
+    assert!(validate::<Strict>(&json!({ "value": true })).is_ok());
+    assert_eq!(
+        validate::<Strict>(&json!({ "value": true, "other": 1 }))
+            .unwrap_err()
+            .to_string(),
+        "Unrecognized key(s) in object: 'other'"
+    );
+    assert!(serde_json::from_value::<Strict>(json!({ "value": true, "other": 1 })).is_err());
+}
+
+#[test]
+fn unknown_keys_override() {
+    assert_eq!(
+        Passthrough::schema(),
+        "z.object({ value: z.boolean() }).passthrough()"
+    );
+    assert_eq!(Overridden::schema(), "z.object({ value: z.boolean() })");
+}
+
+#[test]
+fn flattened_map() {
+    assert_eq!(
+        Extra::schema(),
+        format!(
+            "z.object({{ value: z.boolean() }}).catchall({})",
+            u8::schema()
+        )
+    );
+    assert_eq!(
+        Extra::type_def(),
+        "{ value: boolean } & Record<string, number>"
+    );
+
+    assert_conforms::<Extra>(&Extra {
+        value: true,
+        extra: HashMap::from([(String::from("a"), 1)]),
+    });
+    assert_eq!(
+        validate::<Extra>(&json!({ "value": true, "a": "b" }))
+            .unwrap_err()
+            .to_string(),
+        "a: Expected number, received string"
+    );
+}
+
+#[test]
+fn struct_variants() {
+    let variant = |name| {
+        format!(
+            "z.object({{ type: z.literal(\"{name}\"), id: {} }})",
+            u8::schema()
+        )
+    };
+    assert_eq!(
+        Event::schema(),
+        format!(
+            "z.discriminatedUnion(\"type\", [{}.strict(), {}.passthrough()])",
+            variant("Created"),
+            variant("Deleted")
+        )
+    );
+
+    assert_conforms::<Event>(&Event::Created { id: 1 });
+    assert!(validate::<Event>(&json!({ "type": "Created", "id": 1, "other": 1 })).is_err());
+    assert!(validate::<Event>(&json!({ "type": "Deleted", "id": 1, "other": 1 })).is_ok());
+}
@@ -0,0 +1,251 @@
+use pretty_assertions::assert_eq;
+use zod::ZodType;
+
+mod test_utils;
+use test_utils::*;
+
+#[test]
+fn string_refinements() {
+    test_case! {
+        struct Test {
+            #[zod(min_length = 3, max_length = 64, regex = "^[a-z]+$")]
+            name: String,
+            #[zod(email)]
+            email: String,
+            #[zod(url)]
+            homepage: Option<String>,
+        }
+    }
+
+    assert_eq!(
+        Test::schema(),
+        object!(
+            name: "z.string().min(3).max(64).regex(/^[a-z]+$/)",
+            email: "z.string().email()",
+            homepage: "z.string().url().nullable()"
+        )
+    );
+    assert_eq!(
+        Test::type_def(),
+        "{ name: string, email: string, homepage: string | null }"
+    );
+}
+
+#[test]
+fn number_refinements() {
+    test_case! {
+        struct Test {
+            #[zod(min = 0, max = 100)]
+            percent: f32,
+            #[zod(min = "-1.5")]
+            offset: f64,
+        }
+    }
+
+    assert_eq!(
+        Test::schema(),
+        object!(percent: "z.number().min(0).max(100)", offset: "z.number().min(-1.5)")
+    );
+}
+
+#[test]
+fn non_empty() {
+    test_case! {
+        struct Test(#[zod(non_empty)] Vec<String>, #[zod(non_empty)] String);
+    }
+
+    assert_eq!(
+        Test::schema(),
+        tuple(&["z.array(z.string()).min(1)", "z.string().min(1)"])
+    );
+}
+
+#[test]
+fn enum_variant_fields() {
+    test_case! {
+        enum Test {
+            A {
+                #[zod(max_length = 2)]
+                items: Vec<bool>,
+            },
+            B(#[zod(min_length = 1)] String),
+        }
+    }
+
+    assert_eq!(
+        Test::schema(),
+        zod_union(&[
+            object!(A: object!(items: "z.array(z.boolean()).max(2)")),
+            object!(B: "z.string().min(1)")
+        ])
+    );
+    assert_eq!(
+        Test::input_schema(),
+        zod_union(&[
+            object!(A: object!(items: "z.array(z.boolean()).max(2)")),
+            object!(B: "z.string().min(1)")
+        ])
+    );
+}
+
+#[test]
+fn validate_struct() {
+    use zod::validation::Validate;
+
+    test_case! {
+        #[zod(validate)]
+        struct Test {
+            #[zod(min_length = 3)]
+            #[serde(rename = "userName")]
+            name: String,
+            #[zod(email)]
+            email: Option<String>,
+            #[zod(min = 18)]
+            age: u8,
+        }
+    }
+
+    let valid = Test {
+        name: String::from("abc"),
+        email: None,
+        age: 18,
+    };
+    assert_eq!(valid.validate(), Ok(()));
+
+    let invalid = Test {
+        name: String::from("ab"),
+        email: Some(String::from("ab")),
+        age: 17,
+    };
+    assert_eq!(
+        invalid.validate().unwrap_err().to_string(),
+        "userName: must contain at least 3 character(s), email: invalid email, age: must be greater than or equal to 18"
+    );
+}
+
+#[test]
+fn validate_nested() {
+    use zod::validation::Validate;
+
+    #[derive(zod::Namespace)]
+    #[namespace(name = "Ns")]
+    struct Ns {}
+
+    #[derive(zod::Zod, serde::Deserialize)]
+    #[zod(namespace = "Ns", validate)]
+    struct Inner(#[zod(non_empty)] String, u8);
+
+    #[derive(zod::Zod, serde::Deserialize)]
+    #[zod(namespace = "Ns", validate)]
+    struct Outer {
+        items: Vec<Inner>,
+        #[serde(flatten)]
+        flat: Flat,
+    }
+
+    #[derive(zod::Zod, serde::Deserialize)]
+    #[zod(namespace = "Ns", validate)]
+    struct Flat {
+        #[zod(max = 10)]
+        count: i32,
+    }
+
+    let value = Outer {
+        items: vec![Inner(String::from("a"), 0), Inner(String::new(), 0)],
+        flat: Flat { count: 11 },
+    };
+
+    assert_eq!(
+        value.validate().unwrap_err().to_string(),
+        "items[1][0]: must not be empty, count: must be less than or equal to 10"
+    );
+}
+
+#[test]
+fn validate_generic() {
+    use zod::validation::Validate;
+
+    #[derive(zod::Namespace)]
+    #[namespace(name = "Ns")]
+    struct Ns {}
+
+    #[derive(zod::Zod, serde::Deserialize)]
+    #[zod(namespace = "Ns", validate)]
+    struct User {
+        #[zod(non_empty)]
+        name: String,
+    }
+
+    #[derive(zod::Zod, serde::Deserialize)]
+    #[zod(namespace = "Ns", validate)]
+    struct Page<T> {
+        #[zod(max_length = 1)]
+        items: Vec<T>,
+    }
+
+    let users = Page {
+        items: vec![User {
+            name: String::new(),
+        }],
+    };
+    assert_eq!(
+        users.validate().unwrap_err().to_string(),
+        "items[0].name: must not be empty"
+    );
+
+    let strings = Page {
+        items: vec![String::from("a"), String::from("b")],
+    };
+    assert_eq!(
+        strings.validate().unwrap_err().to_string(),
+        "items: must contain at most 1 element(s)"
+    );
+}
+
+#[test]
+fn validate_enum() {
+    use zod::validation::Validate;
+
+    #[derive(zod::Namespace)]
+    #[namespace(name = "Ns")]
+    struct Ns {}
+
+    #[derive(zod::Zod, serde::Deserialize)]
+    #[zod(namespace = "Ns", validate)]
+    enum External {
+        A(#[zod(url)] String),
+        B {
+            #[zod(max_length = 1)]
+            items: Vec<u8>,
+        },
+    }
+
+    #[derive(zod::Zod, serde::Deserialize)]
+    #[zod(namespace = "Ns", validate)]
+    #[serde(tag = "type", content = "content")]
+    enum Adjacent {
+        A(#[zod(url)] String, u8),
+    }
+
+    assert_eq!(
+        External::A(String::from("nope"))
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        "A: invalid url"
+    );
+    assert_eq!(
+        External::B { items: vec![1, 2] }
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        "B.items: must contain at most 1 element(s)"
+    );
+    assert_eq!(
+        Adjacent::A(String::from("nope"), 0)
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        "content[0]: invalid url"
+    );
+}